@@ -1,14 +1,62 @@
 // Constants for the bonding curve system
+
+use anchor_lang::prelude::Pubkey;
+
+// PDA seed prefixes (see utils::pda for the matching derivations)
+pub const POOL_SEED: &[u8] = b"bonding-curve-pool";
+pub const NFT_ESCROW_SEED: &[u8] = b"nft-escrow";
+pub const AUDIT_LOG_SEED: &[u8] = b"audit-log";
+pub const COLLECTION_STATS_SEED: &[u8] = b"collection-stats";
+pub const MINT_COUNTER_SEED: &[u8] = b"mint-counter";
+
+// Carried by every event; bump whenever any event's fields change
+pub const EVENT_SCHEMA_VERSION: u8 = 6;
+
+// Written to every pool; bump (and extend migrate_pool_account) whenever
+// BondingCurvePool's fields change. v1 is the original fixed layout
+// (BondingCurvePoolV1), which predates the layout_version field
+pub const POOL_LAYOUT_VERSION: u8 = 2;
+
 pub const PRECISION: u64 = 1_000_000; // 6 decimal precision
 pub const GROWTH_FACTOR_PRECISION: u64 = 100_000_000_000; // Higher precision for small growth factor
 pub const DEFAULT_GROWTH_FACTOR: u64 = 3606; // 0.00003606 * GROWTH_FACTOR_PRECISION
+pub const CURVE_SCALING_FACTOR: u64 = 1_000_000; // Fixed-point scale for market cap curve math
 pub const THRESHOLD_MARKET_CAP: u64 = 690 * PRECISION;
 // $69k market cap threshold
 
+// Curve steps calculate_supply_for_price walks before giving up, keeping
+// view_supply_for_price inside the compute budget on slow-growth curves
+pub const MAX_SUPPLY_SEARCH_STEPS: u64 = 2_000;
+
+// Platform treasury; every pool sends its mint fees here. create_pool only
+// accepts it while it holds at least the rent-exempt minimum, since fee
+// transfers into an unfunded account would fail
+// c31WJz1oqhbjqoHnfMqoebVmZvZJ9BrPSqZPT26LwA7
+pub const PLATFORM_WALLET: Pubkey = Pubkey::new_from_array([
+    8, 249, 153, 125, 216, 93, 109, 61, 241, 160, 173, 59, 4, 212, 72, 40, 141, 153, 111, 128, 5,
+    41, 104, 47, 198, 82, 38, 2, 27, 44, 189, 16,
+]);
+
 // Fee structure constants
 pub const MINT_FEE_PERCENTAGE: u64 = 1; // 1% platform fee for minting
+pub const DEFAULT_MINT_FEE_BP: u16 = 100; // Per-pool mint fee a new pool starts with (1%)
+pub const MAX_MINT_FEE_BP: u16 = 1000; // Highest mint fee a creator can configure (10%)
+pub const SELL_FEE_PERCENTAGE: u64 = 5; // 5% of the escrow goes to the pool creator on sell
 pub const CREATOR_ROYALTY_PERCENTAGE: u64 = 5; // 5% creator royalty for secondary sales
 pub const SECONDARY_BURN_PERCENTAGE: u64 = 15; // 1.5% burn for secondary sales (scaled by 10)
 pub const SECONDARY_DISTRIBUTE_PERCENTAGE: u64 = 15; // 1.5% distribute to holders for secondary sales (scaled by 10)
 pub const BUYBACK_BURN_PERCENTAGE: u64 = 25; // 2.5% burn for buybacks (scaled by 10)
 pub const BUYBACK_DISTRIBUTE_PERCENTAGE: u64 = 25; // 2.5% distribute to holders for buybacks (scaled by 10)
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_wallet_matches_its_address() {
+        assert_eq!(
+            PLATFORM_WALLET.to_string(),
+            "c31WJz1oqhbjqoHnfMqoebVmZvZJ9BrPSqZPT26LwA7"
+        );
+    }
+}
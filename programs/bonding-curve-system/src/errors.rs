@@ -37,5 +37,70 @@ pub enum ErrorCode {
 
     #[msg("Escrow account not empty after transfer")] // Added new error code
     EscrowNotEmpty,
-}
 
+    #[msg("Pool is paused")]
+    PoolPaused,
+
+    #[msg("Pool is not paused")]
+    PoolNotPaused,
+
+    #[msg("Pause reason too long")]
+    PauseReasonTooLong,
+
+    #[msg("Auto-unpause time must be in the future")]
+    InvalidPauseExpiry,
+
+    #[msg("Invalid pause operations mask")]
+    InvalidPauseMask,
+
+    #[msg("Curve price exceeds the maximum representable amount")]
+    PriceCalculationOverflow,
+
+    #[msg("Pool still has minted NFTs")]
+    PoolNotEmpty,
+
+    #[msg("Price moved beyond the allowed slippage")]
+    SlippageExceeded,
+
+    #[msg("Pool has not been migrated")]
+    PoolNotMigrated,
+
+    #[msg("Mint fee exceeds the maximum allowed basis points")]
+    InvalidMintFee,
+
+    #[msg("Wallet must wait for the mint cooldown to pass")]
+    CooldownActive,
+
+    #[msg("NFT is not a verified member of the pool's collection")]
+    InvalidCollection,
+
+    #[msg("NFT has not been held for the pool's minimum hold time")]
+    HoldPeriodActive,
+
+    #[msg("Pool account layout is out of date; run migrate_pool_account")]
+    PoolLayoutOutdated,
+
+    #[msg("Pool account layout is already current")]
+    PoolLayoutCurrent,
+
+    #[msg("Platform wallet must hold at least the rent-exempt minimum")]
+    PlatformWalletUnfunded,
+
+    #[msg("Pool has an audit log, which must be passed")]
+    AuditLogRequired,
+
+    #[msg("Pool has collection stats, which must be passed")]
+    CollectionStatsRequired,
+
+    #[msg("Escrow account layout is out of date; run migrate_escrow_account")]
+    EscrowLayoutOutdated,
+
+    #[msg("Escrow account layout is already current")]
+    EscrowLayoutCurrent,
+
+    #[msg("Target price is further along the curve than the supply search walks")]
+    SupplySearchLimitExceeded,
+
+    #[msg("Rent refund must go to the account that paid it on wrap")]
+    InvalidRentPayer,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// Emitted on every mint and sell so indexers can chart the curve
+#[event]
+pub struct PriceHistoryEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub supply: u64,     // Pool supply after the trade
+    pub old_price: u64,  // Curve price for the next mint before the trade
+    pub new_price: u64,  // Curve price for the next mint after the trade
+    pub timestamp: i64,
+}
+
+// Emitted whenever a trade updates a collection's CollectionStats account
+#[event]
+pub struct CollectionStatsUpdatedEvent {
+    pub schema_version: u8,
+    pub collection: Pubkey,
+    pub total_volume: u64,
+    pub total_sales: u64,
+    pub total_platform_fees: u64,
+    pub total_collection_fees: u64,
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
-use crate::state::BondingCurvePool;
+use mpl_token_metadata::accounts::Metadata;
+use crate::constants::{POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::state::{BondingCurvePool, PauseState};
 
 #[derive(Accounts)]
 pub struct BuyNft<'info> {
@@ -17,6 +19,14 @@ pub struct BuyNft<'info> {
     pub nft_data: Account<'info, crate::state::NFTData>,
     
     pub nft_mint: Account<'info, anchor_spl::token::Mint>,
+
+    /// CHECK: This is the NFT's metadata PDA; its collection is checked in buy_nft
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), nft_mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub seller_nft_token_account: Account<'info, anchor_spl::token::TokenAccount>,
@@ -24,14 +34,42 @@ pub struct BuyNft<'info> {
     #[account(mut)]
     pub buyer_nft_token_account: Account<'info, anchor_spl::token::TokenAccount>,
     
-    #[account(mut)]
-    pub pool: Account<'info, BondingCurvePool>,
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ crate::errors::ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Box<Account<'info, BondingCurvePool>>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, anchor_spl::token::Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn buy_nft(ctx: Context<BuyNft>) -> Result<()> {
+    require!(
+        !ctx.accounts.pool.is_migrated_to_tensor,
+        crate::errors::ErrorCode::AlreadyMigrated
+    );
+    require!(
+        !ctx.accounts.pool.is_operation_paused(PauseState::BUY, Clock::get()?.unix_timestamp),
+        crate::errors::ErrorCode::PoolPaused
+    );
+    
+    // Only NFTs verified into the pool's collection trade through it
+    let metadata = Metadata::try_from(&ctx.accounts.metadata_account.to_account_info())
+        .map_err(|_| error!(crate::errors::ErrorCode::InvalidCollection))?;
+    require!(
+        metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified && collection.key == ctx.accounts.pool.collection),
+        crate::errors::ErrorCode::InvalidCollection
+    );
+    
     // Verify NFT ownership
     require!(
         ctx.accounts.nft_data.owner == ctx.accounts.seller_account.key(),
@@ -83,7 +121,9 @@ pub fn buy_nft(ctx: Context<BuyNft>) -> Result<()> {
     
     // Update NFT data
     ctx.accounts.nft_data.owner = ctx.accounts.buyer.key();
+    let now = Clock::get()?.unix_timestamp;
     ctx.accounts.nft_data.last_price = price;
+    ctx.accounts.pool.record_price(price, now);
     
     // Update buyer account
     ctx.accounts.buyer_account.owned_nfts.push(ctx.accounts.nft_data.key());
@@ -161,3 +201,4 @@ fn should_set_past_threshold(pool: &BondingCurvePool, transaction_amount: u64) -
     let new_market_cap = pool.current_market_cap.saturating_add(transaction_amount);
     new_market_cap > 1_000_000_000 && pool.total_supply > 1_000_000
 }
+
@@ -0,0 +1,71 @@
+use crate::constants::{AUDIT_LOG_SEED, COLLECTION_STATS_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{AuditLog, BondingCurvePool, CollectionStats};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// Audit trail; required (and closed with the pool) when the pool has one
+    #[account(
+        mut,
+        close = authority,
+        seeds = [AUDIT_LOG_SEED, pool.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+
+    /// Lifetime totals; required (and closed with the pool) when the pool has them
+    #[account(
+        mut,
+        close = authority,
+        seeds = [COLLECTION_STATS_SEED, collection_mint.key().as_ref()],
+        bump = collection_stats.bump,
+    )]
+    pub collection_stats: Option<Account<'info, CollectionStats>>,
+}
+
+pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+    // Escrows only back NFTs that still exist, so an empty pool owes nothing.
+    // Migrated pools are held to the same rule rather than closed early: their
+    // escrows still hold each outstanding NFT's SOL, which belongs to the
+    // holders and is not swept to the creator, and closing the pool would
+    // free its PDA for create_pool to re-initialize under those escrows
+    let pool = &ctx.accounts.pool;
+    require!(pool.current_supply == 0, ErrorCode::PoolNotEmpty);
+    // Left behind, these would outlive the pool and block a re-created
+    // pool from initializing its own
+    require!(
+        !pool.has_audit_log || ctx.accounts.audit_log.is_some(),
+        ErrorCode::AuditLogRequired
+    );
+    require!(
+        !pool.has_collection_stats || ctx.accounts.collection_stats.is_some(),
+        ErrorCode::CollectionStatsRequired
+    );
+
+    // `close = authority` sweeps every lamport on the pool account, rent and
+    // any residual SOL alike, to the creator
+    msg!(
+        "Pool {} closed, {} lamports returned to creator",
+        pool.key(),
+        pool.to_account_info().lamports()
+    );
+
+    Ok(())
+}
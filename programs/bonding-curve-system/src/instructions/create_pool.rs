@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{DEFAULT_MINT_FEE_BP, PLATFORM_WALLET, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
 use crate::state::BondingCurvePool;
 
 #[derive(Accounts)]
@@ -14,10 +16,14 @@ pub struct CreatePool<'info> {
         init,
         payer = creator,
         space = BondingCurvePool::SPACE,
-        seeds = [b"bonding-curve-pool", collection_mint.key().as_ref()],
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
         bump
     )]
     pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the platform treasury; only its balance is read
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
     
     pub system_program: Program<'info, System>,
 }
@@ -44,9 +50,18 @@ pub fn create_pool(
     
     // Set protocol fee to 1% (10000 = 1%)
     pool.protocol_fee = 10000;
+
+    // Mint fee starts at 1%; the creator can change it with update_pool_config
+    pool.mint_fee_bp = DEFAULT_MINT_FEE_BP;
     
     // Set the creator
     pool.creator = ctx.accounts.creator.key();
+
+    // Mint fees go to the platform treasury, which the creator cannot change
+    require!(
+        ctx.accounts.platform_wallet.lamports() >= Rent::get()?.minimum_balance(0),
+        ErrorCode::PlatformWalletUnfunded
+    );
     
     // Initialize total escrowed to 0
     pool.total_escrowed = 0;
@@ -56,6 +71,9 @@ pub fn create_pool(
     
     // Store the bump
     pool.bump = ctx.bumps.pool;
+
+    // New pools start on the current account layout
+    pool.layout_version = POOL_LAYOUT_VERSION;
     
     Ok(())
 }
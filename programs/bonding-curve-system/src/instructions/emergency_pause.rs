@@ -0,0 +1,106 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, PauseState};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PoolPausedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub paused_by: Pubkey,
+    pub reason: String,
+    pub paused_at: i64,
+    pub auto_unpause_at: i64, // 0 when the pool must be unpaused manually
+    pub paused_operations: u8,
+}
+
+#[event]
+pub struct PoolUnpausedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub unpaused_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct EmergencyPause<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+}
+
+pub fn emergency_pause(
+    ctx: Context<EmergencyPause>,
+    paused_operations: u8,
+    reason: String,
+    auto_unpause_at: i64,
+) -> Result<()> {
+    require!(
+        paused_operations != 0 && paused_operations & !PauseState::ALL == 0,
+        ErrorCode::InvalidPauseMask
+    );
+    require!(
+        reason.len() <= PauseState::MAX_REASON_LEN,
+        ErrorCode::PauseReasonTooLong
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // Zero means the pause only ends with an explicit unpause
+    require!(
+        auto_unpause_at == 0 || auto_unpause_at > now,
+        ErrorCode::InvalidPauseExpiry
+    );
+
+    let pool = &mut ctx.accounts.pool;
+    pool.pause_state = Some(PauseState {
+        paused_by: ctx.accounts.authority.key(),
+        reason: reason.clone(),
+        paused_at: now,
+        auto_unpause_at,
+        paused_operations,
+    });
+
+    msg!("Pool paused (operations {:#05b}): {}", paused_operations, reason);
+
+    emit!(PoolPausedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        paused_by: ctx.accounts.authority.key(),
+        reason,
+        paused_at: now,
+        auto_unpause_at,
+        paused_operations,
+    });
+
+    Ok(())
+}
+
+pub fn unpause_pool(ctx: Context<EmergencyPause>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // An expired pause can still be cleared so the account reflects reality
+    require!(pool.pause_state.is_some(), ErrorCode::PoolNotPaused);
+    pool.pause_state = None;
+
+    msg!("Pool unpaused");
+
+    emit!(PoolUnpausedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        unpaused_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use crate::constants::{AUDIT_LOG_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{AuditLog, BondingCurvePool};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AuditLog::SPACE,
+        seeds = [AUDIT_LOG_SEED, pool.key().as_ref()],
+        bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+    let audit_log = &mut ctx.accounts.audit_log;
+
+    audit_log.pool = ctx.accounts.pool.key();
+    audit_log.next_index = 0;
+    audit_log.total_entries = 0;
+    audit_log.entries = Vec::with_capacity(AuditLog::CAPACITY);
+    audit_log.bump = ctx.bumps.audit_log;
+
+    // From now on mint and sell must record to it
+    ctx.accounts.pool.has_audit_log = true;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use crate::constants::{COLLECTION_STATS_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, CollectionStats};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeCollectionStats<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = CollectionStats::SPACE,
+        seeds = [COLLECTION_STATS_SEED, collection_mint.key().as_ref()],
+        bump
+    )]
+    pub collection_stats: Account<'info, CollectionStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_collection_stats(ctx: Context<InitializeCollectionStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.collection_stats;
+
+    stats.collection = ctx.accounts.collection_mint.key();
+    stats.total_volume = 0;
+    stats.total_sales = 0;
+    stats.total_platform_fees = 0;
+    stats.total_collection_fees = 0;
+    stats.bump = ctx.bumps.collection_stats;
+
+    // From now on mint and sell must update it
+    ctx.accounts.pool.has_collection_stats = true;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, NFT_ESCROW_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, NftEscrow, NftEscrowV1};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use mpl_token_metadata::accounts::Metadata;
+
+#[event]
+pub struct EscrowLayoutMigratedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct MigrateEscrowAccount<'info> {
+    // Pays the rent for the bytes the new layout adds
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This is the NFT mint used for escrow PDA derivation
+    pub nft_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Read as raw bytes since an outdated layout won't deserialize;
+    /// owner and seeds are checked here, the discriminator in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    /// CHECK: This is the NFT's metadata PDA; its collection is checked in migrate_escrow_account
+    #[account(
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), nft_mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Rewrites a v1 escrow (NftEscrowV1) into the current layout. v1 escrows
+// never recorded their pool, so the NFT's verified metadata collection decides it
+pub fn migrate_escrow_account(ctx: Context<MigrateEscrowAccount>) -> Result<()> {
+    let escrow_info = ctx.accounts.escrow.to_account_info();
+
+    require!(
+        escrow_info.data_len() != NftEscrow::SPACE,
+        ErrorCode::EscrowLayoutCurrent
+    );
+    require!(
+        escrow_info.data_len() == NftEscrowV1::SPACE,
+        ErrorCode::EscrowLayoutOutdated
+    );
+
+    let legacy = {
+        let data = escrow_info.try_borrow_data()?;
+        require!(
+            data[..8] == NftEscrow::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        NftEscrowV1::deserialize(&mut &data[8..])?
+    };
+
+    let metadata = Metadata::try_from(&ctx.accounts.metadata_account.to_account_info())
+        .map_err(|_| error!(ErrorCode::InvalidCollection))?;
+    require!(
+        metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| {
+                collection.verified && collection.key == ctx.accounts.pool.collection
+            }),
+        ErrorCode::InvalidCollection
+    );
+
+    // Only the extra rent is added, so the SOL escrowed above it is unchanged
+    let rent = Rent::get()?;
+    let rent_shortfall = rent
+        .minimum_balance(NftEscrow::SPACE)
+        .saturating_sub(rent.minimum_balance(NftEscrowV1::SPACE));
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: escrow_info.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    escrow_info.realloc(NftEscrow::SPACE, true)?;
+
+    let escrow = legacy.into_current(ctx.accounts.pool.key());
+    escrow.try_serialize(&mut &mut escrow_info.try_borrow_mut_data()?[..])?;
+
+    emit!(EscrowLayoutMigratedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, BondingCurvePoolV1};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+#[event]
+pub struct PoolLayoutMigratedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
+
+#[derive(Accounts)]
+pub struct MigratePoolAccount<'info> {
+    // Pays the rent for the bytes the new layout adds
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Read as raw bytes since an outdated layout won't deserialize;
+    /// owner and seeds are checked here, the discriminator and creator in the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump,
+    )]
+    pub pool: UncheckedAccount<'info>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Rewrites a v1 pool (BondingCurvePoolV1) into the current layout, filling
+// every field added since with its default
+pub fn migrate_pool_account(ctx: Context<MigratePoolAccount>) -> Result<()> {
+    let pool_info = ctx.accounts.pool.to_account_info();
+
+    require!(
+        pool_info.data_len() != BondingCurvePool::SPACE,
+        ErrorCode::PoolLayoutCurrent
+    );
+    // v1 is the only layout that was ever deployed without layout_version
+    require!(
+        pool_info.data_len() == BondingCurvePoolV1::SPACE,
+        ErrorCode::PoolLayoutOutdated
+    );
+
+    let legacy = {
+        let data = pool_info.try_borrow_data()?;
+        require!(
+            data[..8] == BondingCurvePool::DISCRIMINATOR,
+            ErrorCode::InvalidPool
+        );
+        BondingCurvePoolV1::deserialize(&mut &data[8..])?
+    };
+    require!(
+        legacy.creator == ctx.accounts.authority.key(),
+        ErrorCode::InvalidAuthority
+    );
+
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(BondingCurvePool::SPACE)
+        .saturating_sub(pool_info.lamports());
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: pool_info.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+    pool_info.realloc(BondingCurvePool::SPACE, true)?;
+
+    let pool = legacy.into_current();
+    pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Pool migrated to account layout v{}", POOL_LAYOUT_VERSION);
+
+    emit!(PoolLayoutMigratedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool_info.key(),
+        from_version: BondingCurvePoolV1::LAYOUT_VERSION,
+        to_version: POOL_LAYOUT_VERSION,
+    });
+
+    Ok(())
+}
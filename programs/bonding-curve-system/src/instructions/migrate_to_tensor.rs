@@ -1,3 +1,4 @@
+use crate::constants::{POOL_LAYOUT_VERSION, POOL_SEED};
 use crate::errors::ErrorCode;
 use crate::state::BondingCurvePool;
 use anchor_lang::prelude::*;
@@ -9,9 +10,10 @@ pub struct MigrateToTensor<'info> {
 
     #[account(
         mut,
-        seeds = [b"bonding-curve-pool", collection_mint.key().as_ref()],
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
         bump = pool.bump,
-        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
     )]
     pub pool: Account<'info, BondingCurvePool>,
 
@@ -38,6 +40,12 @@ pub fn migrate_to_tensor(ctx: Context<MigrateToTensor>) -> Result<()> {
         ErrorCode::ThresholdNotMet
     );
 
+    // A few very expensive NFTs can reach the threshold on their own
+    require!(
+        pool.current_supply >= pool.min_supply_for_migration,
+        ErrorCode::ThresholdNotMet
+    );
+
     // Freeze pool
     pool.is_active = false;
 
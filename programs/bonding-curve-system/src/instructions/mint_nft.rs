@@ -12,18 +12,40 @@ use mpl_token_metadata::instructions::{
 use mpl_token_metadata::types::{Collection, Creator, DataV2};
 
 use crate::{
+    constants::{
+        AUDIT_LOG_SEED, COLLECTION_STATS_SEED, EVENT_SCHEMA_VERSION, MINT_COUNTER_SEED,
+        NFT_ESCROW_SEED, PLATFORM_WALLET, POOL_LAYOUT_VERSION, POOL_SEED,
+    },
     errors::ErrorCode,
-    math::price_calculation::calculate_mint_price,
-    state::{BondingCurvePool, NftEscrow},
+    events::{CollectionStatsUpdatedEvent, PriceHistoryEvent},
+    math::price_calculation::{calculate_mint_price, calculate_mint_split},
+    state::{
+        AuditAction, AuditLog, BondingCurvePool, CollectionStats, MintCounter, NftEscrow,
+        PauseState,
+    },
 };
 
 #[event]
 pub struct NftMint {
+    pub schema_version: u8,
     pub minter: Pubkey,
     pub nft_mint: Pubkey,
     pub pool: Pubkey,
     pub mint_price: u64,
     pub protocol_fee: u64,
+    pub supply: u64, // Pool supply including this mint
+    pub escrow_amount: u64, // Lamports locked in the NFT's escrow
+    pub usd_estimate: u64, // mint_price in USD cents at the pool's reference price (0 = unset)
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscrowInitializedEvent {
+    pub schema_version: u8,
+    pub nft_mint: Pubkey,
+    pub escrow: Pubkey,
+    pub amount: u64,     // Lamports deposited (mint price minus the protocol fee)
+    pub last_price: u64, // Mint price the escrow was opened at
     pub timestamp: i64,
 }
 
@@ -44,14 +66,30 @@ pub struct MintNFT<'info> {
     #[account(
         init,
         payer = payer,
-        seeds = [b"nft-escrow", nft_mint.key().as_ref()],
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
         bump,
         space = NftEscrow::SPACE,
     )]
     pub escrow: Account<'info, NftEscrow>,
 
-    #[account(mut)]
-    pub pool: Account<'info, BondingCurvePool>,
+    // Bound to collection_mint, so pause and cooldown settings come from the
+    // pool whose collection the NFT is minted into
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Box<Account<'info, BondingCurvePool>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MintCounter::SPACE,
+        seeds = [MINT_COUNTER_SEED, pool.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub mint_counter: Box<Account<'info, MintCounter>>,
 
     /// CHECK: This is the token account for the payer/minter.
     /// It will be created by the AssociatedToken program if it doesn't exist.
@@ -69,7 +107,7 @@ pub struct MintNFT<'info> {
     #[account(mut)]
     pub master_edition: UncheckedAccount<'info>,
 
-    /// CHECK: This is the collection mint
+    /// CHECK: This is the collection mint used for pool PDA derivation
     pub collection_mint: UncheckedAccount<'info>,
 
     /// CHECK: This is the collection metadata account
@@ -81,9 +119,25 @@ pub struct MintNFT<'info> {
     // Associated Token Program required for creating the token account
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    /// CHECK: Creator account from the pool, needs to be mutable to receive funds
-    #[account(mut, address = pool.creator)]
-    pub creator: UncheckedAccount<'info>,
+    /// CHECK: Platform treasury receiving the mint fee, needs to be mutable to receive funds
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    /// Audit trail; required once the pool has one (pool.has_audit_log)
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED, pool.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+
+    /// Lifetime totals; required once the pool has them (pool.has_collection_stats)
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATS_SEED, pool.collection.as_ref()],
+        bump = collection_stats.bump,
+    )]
+    pub collection_stats: Option<Account<'info, CollectionStats>>,
 
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -95,6 +149,7 @@ pub fn mint_nft(
     symbol: String,
     uri: String,
     seller_fee_basis_points: u16,
+    max_price: u64,
 ) -> Result<()> {
     // --- Pricing and Pool Logic (Keep as is) ---
     let price = calculate_mint_price(
@@ -102,47 +157,42 @@ pub fn mint_nft(
         ctx.accounts.pool.growth_factor,
         ctx.accounts.pool.current_supply,
     )?;
+    // Other mints may land first and move the price; 0 disables the cap
+    require!(
+        max_price == 0 || price <= max_price,
+        ErrorCode::SlippageExceeded
+    );
+    // Checked ahead of is_active so a migrated pool reports why it is closed
+    require!(
+        !ctx.accounts.pool.is_migrated_to_tensor,
+        ErrorCode::AlreadyMigrated
+    );
     require!(ctx.accounts.pool.is_active, ErrorCode::PoolInactive);
-    let protocol_fee = price.checked_div(100).ok_or(ErrorCode::MathOverflow)?;
-    let net_price = price
-        .checked_sub(protocol_fee)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    // Transfer SOL to escrow
-    let transfer_to_escrow = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.payer.key(),
-        &ctx.accounts.escrow.key(),
-        net_price,
+    require!(
+        !ctx.accounts.pool.has_audit_log || ctx.accounts.audit_log.is_some(),
+        ErrorCode::AuditLogRequired
     );
-    anchor_lang::solana_program::program::invoke(
-        &transfer_to_escrow,
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.escrow.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-    )?;
-
-    // Transfer protocol fee to pool creator
-    let transfer_to_creator = anchor_lang::solana_program::system_instruction::transfer(
-        &ctx.accounts.payer.key(),
-        &ctx.accounts.pool.creator,
-        protocol_fee,
+    require!(
+        !ctx.accounts.pool.has_collection_stats || ctx.accounts.collection_stats.is_some(),
+        ErrorCode::CollectionStatsRequired
     );
-    anchor_lang::solana_program::program::invoke(
-        &transfer_to_creator,
-        &[
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.creator.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-    )?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !ctx.accounts.pool.is_operation_paused(PauseState::MINT, now),
+        ErrorCode::PoolPaused
+    );
+    require!(
+        !ctx.accounts.mint_counter.is_cooling_down(ctx.accounts.pool.mint_cooldown_seconds, now),
+        ErrorCode::CooldownActive
+    );
+    let (protocol_fee, net_price) = calculate_mint_split(price, ctx.accounts.pool.mint_fee_bp)?;
 
     // Initialize escrow
     ctx.accounts.escrow.nft_mint = ctx.accounts.nft_mint.key();
     ctx.accounts.escrow.lamports = net_price;
     ctx.accounts.escrow.last_price = price;
     ctx.accounts.escrow.bump = ctx.bumps.escrow;
+    ctx.accounts.escrow.pool = ctx.accounts.pool.key();
 
     // Update pool
     ctx.accounts.pool.current_supply = ctx
@@ -157,6 +207,39 @@ pub fn mint_nft(
         .total_escrowed
         .checked_add(net_price)
         .ok_or(ErrorCode::MathOverflow)?;
+    ctx.accounts.pool.total_minted = ctx
+        .accounts
+        .pool
+        .total_minted
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    ctx.accounts.escrow.edition_number = ctx.accounts.pool.total_minted;
+    ctx.accounts.escrow.minted_at = now;
+
+    let mint_counter = &mut ctx.accounts.mint_counter;
+    mint_counter.pool = ctx.accounts.pool.key();
+    mint_counter.minter = ctx.accounts.payer.key();
+    mint_counter.bump = ctx.bumps.mint_counter;
+    mint_counter.record_mint(now)?;
+
+    ctx.accounts.pool.record_price(price, now);
+    ctx.accounts.pool.refresh_market_cap();
+
+    // Saturates when the curve has run past what a u64 can price
+    let next_price = calculate_mint_price(
+        ctx.accounts.pool.base_price,
+        ctx.accounts.pool.growth_factor,
+        ctx.accounts.pool.current_supply,
+    )
+    .unwrap_or(u64::MAX);
+    emit!(PriceHistoryEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        supply: ctx.accounts.pool.current_supply,
+        old_price: price,
+        new_price: next_price,
+        timestamp: now,
+    });
     // --- End Pricing and Pool Logic ---
 
     // --- NFT Creation Logic ---
@@ -257,13 +340,80 @@ pub fn mint_nft(
         ctx.accounts.master_edition.key()
     );
 
+    // --- Payment Routing ---
+    // Every CPI that can fail (metadata, token account, mint, master edition) runs
+    // above, so SOL only moves once the NFT exists
+    // Transfer SOL to escrow
+    let transfer_to_escrow = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.escrow.key(),
+        net_price,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &transfer_to_escrow,
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.escrow.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    // Transfer protocol fee to the platform wallet
+    let transfer_to_platform = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.payer.key(),
+        &PLATFORM_WALLET,
+        protocol_fee,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &transfer_to_platform,
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.platform_wallet.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    emit!(EscrowInitializedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        escrow: ctx.accounts.escrow.key(),
+        amount: ctx.accounts.escrow.lamports,
+        last_price: ctx.accounts.escrow.last_price,
+        timestamp: now,
+    });
+
+    if let Some(audit_log) = ctx.accounts.audit_log.as_mut() {
+        audit_log.record(
+            AuditAction::Mint,
+            ctx.accounts.payer.key(),
+            price,
+            Clock::get()?.slot,
+        )?;
+    }
+
+    if let Some(stats) = ctx.accounts.collection_stats.as_mut() {
+        stats.record_mint(price, protocol_fee)?;
+        emit!(CollectionStatsUpdatedEvent {
+            schema_version: EVENT_SCHEMA_VERSION,
+            collection: stats.collection,
+            total_volume: stats.total_volume,
+            total_sales: stats.total_sales,
+            total_platform_fees: stats.total_platform_fees,
+            total_collection_fees: stats.total_collection_fees,
+        });
+    }
+
     // --- Emit NftMint Event ---
     emit!(NftMint {
+        schema_version: EVENT_SCHEMA_VERSION,
         minter: ctx.accounts.payer.key(),
         nft_mint: ctx.accounts.nft_mint.key(),
         pool: ctx.accounts.pool.key(),
         mint_price: price,
-        protocol_fee: protocol_fee,
+        protocol_fee,
+        supply: ctx.accounts.pool.current_supply,
+        escrow_amount: ctx.accounts.escrow.lamports,
+        usd_estimate: ctx.accounts.pool.usd_estimate(price)?,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
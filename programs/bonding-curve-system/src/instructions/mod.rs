@@ -3,4 +3,20 @@ pub mod buy_nft;
 pub mod mint_nft;
 pub mod migrate_to_tensor;
 pub mod sell_nft;
-pub mod create_collection_nft;
\ No newline at end of file
+pub mod create_collection_nft;
+pub mod emergency_pause;
+pub mod transfer_pool_authority;
+pub mod view_supply_for_price;
+pub mod initialize_audit_log;
+pub mod close_pool;
+pub mod wrap_escrow;
+pub mod update_pool_config;
+pub mod quote_sell;
+pub mod quote_mint;
+pub mod view_price_history;
+pub mod transfer_nft;
+pub mod initialize_collection_stats;
+pub mod verify_collection_item;
+pub mod view_pool_stats;
+pub mod migrate_pool_account;
+pub mod migrate_escrow_account;
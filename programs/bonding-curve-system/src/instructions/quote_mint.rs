@@ -0,0 +1,42 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION};
+use crate::errors::ErrorCode;
+use crate::math::price_calculation::{calculate_mint_price, calculate_mint_split};
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MintQuoteEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub supply: u64,        // Supply the quote was priced at
+    pub price: u64,         // Total the minter pays
+    pub platform_fee: u64,  // Share sent to PLATFORM_WALLET
+    pub escrow_amount: u64, // Share locked in the new NFT's escrow
+}
+
+#[derive(Accounts)]
+pub struct QuoteMint<'info> {
+    #[account(
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+}
+
+// Mirrors mint_nft's pricing and fee split without touching any state
+pub fn quote_mint(ctx: Context<QuoteMint>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let price = calculate_mint_price(pool.base_price, pool.growth_factor, pool.current_supply)?;
+    let (platform_fee, escrow_amount) = calculate_mint_split(price, pool.mint_fee_bp)?;
+
+    emit!(MintQuoteEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        supply: pool.current_supply,
+        price,
+        platform_fee,
+        escrow_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, NFT_ESCROW_SEED, POOL_LAYOUT_VERSION};
+use crate::errors::ErrorCode;
+use crate::math::price_calculation::calculate_sell_proceeds;
+use crate::state::{BondingCurvePool, NftEscrow};
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SellQuoteEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub gross: u64,         // Escrow balance above its rent-exempt minimum
+    pub fee: u64,           // Sell fee paid to the pool creator
+    pub net_to_seller: u64, // What sell_nft pays out, before the escrow rent refund
+}
+
+#[derive(Accounts)]
+pub struct QuoteSell<'info> {
+    #[account(
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the NFT mint used for escrow PDA derivation
+    pub nft_mint: UncheckedAccount<'info>,
+
+    // Same binding as sell_nft, so a quote never mixes two pools' state
+    #[account(
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.pool == pool.key() @ ErrorCode::InvalidPool
+    )]
+    pub escrow: Account<'info, NftEscrow>,
+}
+
+// Mirrors sell_nft's payout math without touching any state
+pub fn quote_sell(ctx: Context<QuoteSell>) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(NftEscrow::SPACE);
+    let (gross, fee, net_to_seller) = calculate_sell_proceeds(
+        ctx.accounts.escrow.to_account_info().lamports(),
+        rent_exempt_minimum,
+    )?;
+
+    emit!(SellQuoteEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        gross,
+        fee,
+        net_to_seller,
+    });
+
+    Ok(())
+}
@@ -3,16 +3,23 @@
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_token_metadata::accounts::Metadata;
 use mpl_token_metadata::instructions::{BurnNftCpi, BurnNftCpiAccounts};
 
 use crate::{
+    constants::{
+        AUDIT_LOG_SEED, COLLECTION_STATS_SEED, EVENT_SCHEMA_VERSION, NFT_ESCROW_SEED,
+        POOL_LAYOUT_VERSION, POOL_SEED,
+    },
     errors::ErrorCode,
-    math::price_calculation::calculate_sell_price,
-    state::{BondingCurvePool, NftEscrow},
+    events::{CollectionStatsUpdatedEvent, PriceHistoryEvent},
+    math::price_calculation::{calculate_mint_price, calculate_sell_price, calculate_sell_proceeds},
+    state::{AuditAction, AuditLog, BondingCurvePool, CollectionStats, NftEscrow, PauseState},
 };
 
 #[event]
 pub struct NftSale {
+    pub schema_version: u8,
     pub seller: Pubkey,
     pub nft_mint: Pubkey,
     pub pool: Pubkey,    // Address of the BondingCurvePool
@@ -26,13 +33,21 @@ pub struct SellNFT<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
-    #[account(mut)]
-    pub pool: Account<'info, BondingCurvePool>,
+    // Bound to collection_mint, so the pause state checked is that collection's
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Box<Account<'info, BondingCurvePool>>,
 
+    // Pool guards (pause, hold period) only apply if the NFT belongs to the pool
     #[account(
         mut,
-        seeds = [b"nft-escrow", nft_mint.key().as_ref()],
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
         bump = escrow.bump,
+        constraint = escrow.pool == pool.key() @ ErrorCode::InvalidPool
     )]
     pub escrow: Account<'info, NftEscrow>,
 
@@ -54,8 +69,13 @@ pub struct SellNFT<'info> {
     /// CHECK: This is the token metadata program
     pub token_metadata_program: UncheckedAccount<'info>,
 
-    #[account(mut)]
-    /// CHECK: This is the metadata account associated with the NFT
+    /// CHECK: This is the NFT's metadata PDA; its collection is checked in sell_nft
+    #[account(
+        mut,
+        seeds = [b"metadata", mpl_token_metadata::ID.as_ref(), nft_mint.key().as_ref()],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
     pub metadata_account: UncheckedAccount<'info>,
 
     #[account(mut)]
@@ -63,18 +83,34 @@ pub struct SellNFT<'info> {
     pub master_edition_account: UncheckedAccount<'info>,
 
     #[account(mut)]
-    /// CHECK: This is the collection mint account.
+    /// CHECK: This is the collection mint used for pool PDA derivation
     pub collection_mint: UncheckedAccount<'info>,
 
     #[account(mut)]
     /// CHECK: This is the collection metadata account
     pub collection_metadata: UncheckedAccount<'info>,
 
+    /// Audit trail; required once the pool has one (pool.has_audit_log)
+    #[account(
+        mut,
+        seeds = [AUDIT_LOG_SEED, pool.key().as_ref()],
+        bump = audit_log.bump,
+    )]
+    pub audit_log: Option<Account<'info, AuditLog>>,
+
+    /// Lifetime totals; required once the pool has them (pool.has_collection_stats)
+    #[account(
+        mut,
+        seeds = [COLLECTION_STATS_SEED, pool.collection.as_ref()],
+        bump = collection_stats.bump,
+    )]
+    pub collection_stats: Option<Account<'info, CollectionStats>>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
+pub fn sell_nft(ctx: Context<SellNFT>, min_proceeds: u64) -> Result<()> {
     let pool_account = &ctx.accounts.pool;
     let price = calculate_sell_price(
         pool_account.base_price,
@@ -83,6 +119,50 @@ pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
     )?;
 
     require!(pool_account.is_active, ErrorCode::PoolInactive);
+    require!(
+        !pool_account.has_audit_log || ctx.accounts.audit_log.is_some(),
+        ErrorCode::AuditLogRequired
+    );
+    require!(
+        !pool_account.has_collection_stats || ctx.accounts.collection_stats.is_some(),
+        ErrorCode::CollectionStatsRequired
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !pool_account.is_operation_paused(PauseState::SELL, now),
+        ErrorCode::PoolPaused
+    );
+    // Discourages minting and immediately dumping back into the curve
+    require!(
+        now.saturating_sub(ctx.accounts.escrow.minted_at) >= pool_account.min_hold_seconds,
+        ErrorCode::HoldPeriodActive
+    );
+
+    // The pool tracks what mints actually escrowed (price net of the mint fee),
+    // so release that same amount rather than the curve price
+    let escrowed_amount = ctx.accounts.escrow.lamports;
+    if pool_account.total_escrowed < escrowed_amount {
+        msg!(
+            "Error: Pool tracks {} escrowed lamports but this NFT's escrow holds {}",
+            pool_account.total_escrowed,
+            escrowed_amount
+        );
+        return err!(ErrorCode::InsufficientEscrowBalance);
+    }
+
+    // mint_nft names the pool's collection on every NFT, but anyone can name it;
+    // only NFTs the creator has verified (verify_collection_item) sell back
+    let metadata = Metadata::try_from(&ctx.accounts.metadata_account.to_account_info())
+        .map_err(|_| error!(ErrorCode::InvalidCollection))?;
+    require!(
+        metadata
+            .collection
+            .as_ref()
+            .is_some_and(|collection| {
+                collection.verified && collection.key == pool_account.collection
+            }),
+        ErrorCode::InvalidCollection
+    );
 
     let collection_metadata_info = ctx.accounts.collection_metadata.to_account_info();
 
@@ -109,19 +189,14 @@ pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
     let escrow_total_lamports = escrow_info.lamports();
     let rent_exempt_minimum = Rent::get()?.minimum_balance(NftEscrow::SPACE);
 
-    let available_lamports = escrow_total_lamports
-        .checked_sub(rent_exempt_minimum)
-        .unwrap_or(0);
+    let (_, sell_fee_calculated, net_amount_to_seller_calculated) =
+        calculate_sell_proceeds(escrow_total_lamports, rent_exempt_minimum)?;
 
-    let sell_fee_calculated = available_lamports
-        .checked_mul(5) // Assuming 5% fee, this should be configurable or from pool state if dynamic
-        .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(100)
-        .ok_or(ErrorCode::MathOverflow)?;
-
-    let net_amount_to_seller_calculated = available_lamports
-        .checked_sub(sell_fee_calculated)
-        .ok_or(ErrorCode::MathOverflow)?;
+    // Guards against a fee or escrow change between quote and execution
+    require!(
+        net_amount_to_seller_calculated >= min_proceeds,
+        ErrorCode::SlippageExceeded
+    );
 
     let total_payout_amount = sell_fee_calculated
         .checked_add(net_amount_to_seller_calculated)
@@ -139,14 +214,6 @@ pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
         .checked_add(rent_exempt_minimum)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    let nft_mint_key = ctx.accounts.nft_mint.key();
-    let escrow_bump = ctx.accounts.escrow.bump;
-    let seeds = &[
-        b"nft-escrow".as_ref(),
-        nft_mint_key.as_ref(),
-        &[escrow_bump],
-    ];
-
     let escrow_account_info_for_zeroing = ctx.accounts.escrow.to_account_info();
     let mut escrow_data = escrow_account_info_for_zeroing.try_borrow_mut_data()?;
     escrow_data.fill(0);
@@ -180,10 +247,51 @@ pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
         .accounts
         .pool
         .total_escrowed
-        .checked_sub(price)
+        .checked_sub(escrowed_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
+    ctx.accounts.pool.record_price(price, now);
+    ctx.accounts.pool.refresh_market_cap();
+
+    // Saturates when the curve has run past what a u64 can price
+    let previous_mint_price = calculate_mint_price(
+        ctx.accounts.pool.base_price,
+        ctx.accounts.pool.growth_factor,
+        ctx.accounts.pool.current_supply + 1,
+    )
+    .unwrap_or(u64::MAX);
+    emit!(PriceHistoryEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        supply: ctx.accounts.pool.current_supply,
+        old_price: previous_mint_price,
+        new_price: price,
+        timestamp: now,
+    });
+
+    if let Some(audit_log) = ctx.accounts.audit_log.as_mut() {
+        audit_log.record(
+            AuditAction::Sell,
+            ctx.accounts.seller.key(),
+            net_amount_to_seller_calculated,
+            Clock::get()?.slot,
+        )?;
+    }
+
+    if let Some(stats) = ctx.accounts.collection_stats.as_mut() {
+        stats.record_sale(total_payout_amount, sell_fee_calculated)?;
+        emit!(CollectionStatsUpdatedEvent {
+            schema_version: EVENT_SCHEMA_VERSION,
+            collection: stats.collection,
+            total_volume: stats.total_volume,
+            total_sales: stats.total_sales,
+            total_platform_fees: stats.total_platform_fees,
+            total_collection_fees: stats.total_collection_fees,
+        });
+    }
+
     emit!(NftSale {
+        schema_version: EVENT_SCHEMA_VERSION,
         seller: ctx.accounts.seller.key(),
         nft_mint: ctx.accounts.nft_mint.key(),
         pool: ctx.accounts.pool.key(),
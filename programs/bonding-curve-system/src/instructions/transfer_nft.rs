@@ -0,0 +1,77 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, NFT_ESCROW_SEED};
+use crate::state::NftEscrow;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+#[event]
+pub struct NftTransferredEvent {
+    pub schema_version: u8,
+    pub nft_mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct TransferNft<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Any wallet can receive the NFT; only its ATA is written to
+    pub recipient: UncheckedAccount<'info>,
+
+    pub nft_mint: Account<'info, Mint>,
+
+    // Only pool NFTs have an escrow; it is read, never modified
+    #[account(
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, NftEscrow>,
+
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = nft_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        1, // NFTs have amount of 1
+    )?;
+
+    msg!("NFT transferred to {}", ctx.accounts.recipient.key());
+
+    emit!(NftTransferredEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        nft_mint: ctx.accounts.nft_mint.key(),
+        from: ctx.accounts.owner.key(),
+        to: ctx.accounts.recipient.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PoolAuthorityTransferredEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub previous_creator: Pubkey,
+    pub new_creator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct TransferPoolAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptPoolAuthority<'info> {
+    pub new_creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.pending_creator == Some(new_creator.key()) @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+}
+
+pub fn transfer_pool_authority(
+    ctx: Context<TransferPoolAuthority>,
+    new_creator: Pubkey,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    // Nothing changes hands until the new creator signs accept_pool_authority
+    pool.pending_creator = Some(new_creator);
+
+    msg!("Pool authority transfer proposed to {}", new_creator);
+
+    Ok(())
+}
+
+pub fn accept_pool_authority(ctx: Context<AcceptPoolAuthority>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    let previous_creator = pool.creator;
+    pool.creator = ctx.accounts.new_creator.key();
+    pool.pending_creator = None;
+
+    msg!("Pool authority accepted by {}", pool.creator);
+
+    emit!(PoolAuthorityTransferredEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        previous_creator,
+        new_creator: pool.creator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, MAX_MINT_FEE_BP, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PoolConfigUpdatedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub min_supply_for_migration: u64,
+    pub mint_fee_bp: u16,
+    pub reference_price: u64,
+    pub mint_cooldown_seconds: i64,
+    pub min_hold_seconds: i64,
+}
+
+// Creator-tunable pool settings; None leaves a setting unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdatePoolConfigArgs {
+    pub min_supply_for_migration: Option<u64>,
+    pub mint_fee_bp: Option<u16>,            // Basis points, at most MAX_MINT_FEE_BP
+    pub reference_price: Option<u64>,        // USD cents per SOL, 0 = no USD estimates
+    pub mint_cooldown_seconds: Option<i64>,  // Per-wallet gap between mints, 0 = none
+    pub min_hold_seconds: Option<i64>,       // Time an NFT must be held before selling, 0 = none
+}
+
+#[derive(Accounts)]
+pub struct UpdatePoolConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+}
+
+// Each setting is optional; None leaves the current value untouched
+pub fn update_pool_config(ctx: Context<UpdatePoolConfig>, args: UpdatePoolConfigArgs) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+
+    if let Some(min_supply) = args.min_supply_for_migration {
+        pool.min_supply_for_migration = min_supply;
+    }
+
+    if let Some(fee_bp) = args.mint_fee_bp {
+        require!(fee_bp <= MAX_MINT_FEE_BP, ErrorCode::InvalidMintFee);
+        pool.mint_fee_bp = fee_bp;
+    }
+
+    if let Some(cents_per_sol) = args.reference_price {
+        pool.reference_price = cents_per_sol;
+    }
+
+    if let Some(cooldown) = args.mint_cooldown_seconds {
+        require!(cooldown >= 0, ErrorCode::InvalidAmount);
+        pool.mint_cooldown_seconds = cooldown;
+    }
+
+    if let Some(hold) = args.min_hold_seconds {
+        require!(hold >= 0, ErrorCode::InvalidAmount);
+        pool.min_hold_seconds = hold;
+    }
+
+    emit!(PoolConfigUpdatedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        min_supply_for_migration: pool.min_supply_for_migration,
+        mint_fee_bp: pool.mint_fee_bp,
+        reference_price: pool.reference_price,
+        mint_cooldown_seconds: pool.mint_cooldown_seconds,
+        min_hold_seconds: pool.min_hold_seconds,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::constants::{NFT_ESCROW_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, NftEscrow};
+use anchor_lang::prelude::*;
+use mpl_token_metadata::instructions::{
+    VerifySizedCollectionItemCpi, VerifySizedCollectionItemCpiAccounts,
+};
+
+#[derive(Accounts)]
+pub struct VerifyCollectionItem<'info> {
+    // The collection's update authority, which create_collection_nft sets to its payer
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This is the collection metadata account, checked by the metadata program
+    #[account(mut)]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: This is the collection master edition, checked by the metadata program
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: This is the NFT mint used for escrow PDA derivation
+    pub nft_mint: UncheckedAccount<'info>,
+
+    // Only NFTs minted through a pool have an escrow
+    #[account(
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, NftEscrow>,
+
+    /// CHECK: This is the NFT's metadata account, checked by the metadata program
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    #[account(address = mpl_token_metadata::ID)]
+    /// CHECK: This is the token metadata program
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+// Marks a pool NFT as a verified member of its sized collection
+pub fn verify_collection_item(ctx: Context<VerifyCollectionItem>) -> Result<()> {
+    let verify_accounts = VerifySizedCollectionItemCpiAccounts {
+        metadata: &ctx.accounts.metadata_account.to_account_info(),
+        collection_authority: &ctx.accounts.authority.to_account_info(),
+        payer: &ctx.accounts.authority.to_account_info(),
+        collection_mint: &ctx.accounts.collection_mint.to_account_info(),
+        collection: &ctx.accounts.collection_metadata.to_account_info(),
+        collection_master_edition_account: &ctx
+            .accounts
+            .collection_master_edition
+            .to_account_info(),
+        collection_authority_record: None,
+    };
+
+    VerifySizedCollectionItemCpi::new(
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        verify_accounts,
+    )
+    .invoke()?;
+
+    msg!("NFT {} verified in collection", ctx.accounts.nft_mint.key());
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION};
+use crate::errors::ErrorCode;
+use crate::math::price_calculation::calculate_backing_ratio_bp;
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PoolStatsEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub current_supply: u64,
+    pub total_escrowed: u64,
+    pub market_cap: u64,       // current_supply * most recent mint price
+    pub backing_ratio_bp: u16, // total_escrowed / market_cap, capped at 10000
+}
+
+#[derive(Accounts)]
+pub struct ViewPoolStats<'info> {
+    #[account(
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+}
+
+pub fn view_pool_stats(ctx: Context<ViewPoolStats>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let market_cap = pool.market_cap()?;
+
+    emit!(PoolStatsEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        current_supply: pool.current_supply,
+        total_escrowed: pool.total_escrowed,
+        market_cap,
+        backing_ratio_bp: calculate_backing_ratio_bp(pool.total_escrowed, market_cap),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION};
+use crate::errors::ErrorCode;
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PriceHistorySnapshotEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub prices: Vec<u64>,     // Executed prices, oldest first
+    pub timestamps: Vec<i64>, // Matching timestamp for each price
+}
+
+#[derive(Accounts)]
+pub struct ViewPriceHistory<'info> {
+    #[account(
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+}
+
+pub fn view_price_history(ctx: Context<ViewPriceHistory>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let (timestamps, prices) = pool.price_history_chronological().into_iter().unzip();
+
+    emit!(PriceHistorySnapshotEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        prices,
+        timestamps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, POOL_LAYOUT_VERSION};
+use crate::errors::ErrorCode;
+use crate::math::price_calculation::{calculate_mint_price, calculate_supply_for_price};
+use crate::state::BondingCurvePool;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SupplyForPriceEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub target_price: u64,
+    pub supply: u64,           // Smallest supply whose mint price reaches target_price
+    pub price_at_supply: u64,  // Mint price at that supply (u64::MAX if it no longer fits)
+}
+
+#[derive(Accounts)]
+pub struct ViewSupplyForPrice<'info> {
+    #[account(
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+}
+
+pub fn view_supply_for_price(ctx: Context<ViewSupplyForPrice>, target_price: u64) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+
+    let supply = calculate_supply_for_price(pool.base_price, pool.growth_factor, target_price)?;
+    let price_at_supply =
+        calculate_mint_price(pool.base_price, pool.growth_factor, supply).unwrap_or(u64::MAX);
+
+    emit!(SupplyForPriceEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: pool.key(),
+        target_price,
+        supply,
+        price_at_supply,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::constants::{EVENT_SCHEMA_VERSION, NFT_ESCROW_SEED, POOL_LAYOUT_VERSION, POOL_SEED};
+use crate::errors::ErrorCode;
+use crate::state::{BondingCurvePool, NftEscrow};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, SyncNative, Token, TokenAccount};
+
+#[event]
+pub struct EscrowWrappedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowUnwrappedEvent {
+    pub schema_version: u8,
+    pub pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WrapEscrow<'info> {
+    // Pays the rent of the wSOL account when it is created; recorded on the
+    // escrow so unwrap refunds that payer even if the pool changes hands
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This is the NFT mint used for escrow PDA derivation
+    pub nft_mint: UncheckedAccount<'info>,
+
+    // Only the pool that minted the NFT may move its escrow
+    #[account(
+        mut,
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.pool == pool.key() @ ErrorCode::InvalidPool
+    )]
+    pub escrow: Account<'info, NftEscrow>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = native_mint,
+        associated_token::authority = escrow,
+    )]
+    pub escrow_wsol: Account<'info, TokenAccount>,
+
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn wrap_escrow(ctx: Context<WrapEscrow>) -> Result<()> {
+    // Sells pay out of the escrow's lamports, so only frozen (migrated) pools may wrap
+    require!(ctx.accounts.pool.is_migrated_to_tensor, ErrorCode::PoolNotMigrated);
+
+    let escrow_info = ctx.accounts.escrow.to_account_info();
+    let wsol_info = ctx.accounts.escrow_wsol.to_account_info();
+
+    // The escrow keeps its rent-exempt minimum so the account stays alive
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(NftEscrow::SPACE);
+    let amount = escrow_info.lamports().saturating_sub(rent_exempt_minimum);
+    require!(amount > 0, ErrorCode::InsufficientEscrowBalance);
+
+    **escrow_info.try_borrow_mut_lamports()? -= amount;
+    **wsol_info.try_borrow_mut_lamports()? += amount;
+
+    if ctx.accounts.escrow.wsol_rent_payer.is_none() {
+        ctx.accounts.escrow.wsol_rent_payer = Some(ctx.accounts.authority.key());
+    }
+
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: wsol_info,
+        },
+    ))?;
+
+    msg!("Wrapped {} lamports of escrow into wSOL", amount);
+
+    emit!(EscrowWrappedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        nft_mint: ctx.accounts.nft_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnwrapEscrow<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [POOL_SEED, collection_mint.key().as_ref()],
+        bump = pool.bump,
+        constraint = pool.creator == authority.key() @ ErrorCode::InvalidAuthority,
+        constraint = pool.layout_version == POOL_LAYOUT_VERSION @ ErrorCode::PoolLayoutOutdated
+    )]
+    pub pool: Account<'info, BondingCurvePool>,
+
+    /// CHECK: This is the collection mint used for pool PDA derivation
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: This is the NFT mint used for escrow PDA derivation
+    pub nft_mint: UncheckedAccount<'info>,
+
+    // Only the pool that minted the NFT may move its escrow
+    #[account(
+        mut,
+        seeds = [NFT_ESCROW_SEED, nft_mint.key().as_ref()],
+        bump = escrow.bump,
+        constraint = escrow.pool == pool.key() @ ErrorCode::InvalidPool
+    )]
+    pub escrow: Account<'info, NftEscrow>,
+
+    #[account(
+        mut,
+        associated_token::mint = native_mint,
+        associated_token::authority = escrow,
+    )]
+    pub escrow_wsol: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the wSOL account's rent; must be the recorded wrap payer
+    #[account(
+        mut,
+        constraint = escrow.wsol_rent_payer == Some(rent_payer.key()) @ ErrorCode::InvalidRentPayer
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(address = token::spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn unwrap_escrow(ctx: Context<UnwrapEscrow>) -> Result<()> {
+    let amount = ctx.accounts.escrow_wsol.amount;
+    let wsol_rent = ctx
+        .accounts
+        .escrow_wsol
+        .to_account_info()
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let nft_mint_key = ctx.accounts.nft_mint.key();
+    let seeds = &[
+        NFT_ESCROW_SEED,
+        nft_mint_key.as_ref(),
+        &[ctx.accounts.escrow.bump],
+    ];
+
+    // Closing a native account releases both the wrapped balance and its rent
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_wsol.to_account_info(),
+            destination: ctx.accounts.escrow.to_account_info(),
+            authority: ctx.accounts.escrow.to_account_info(),
+        },
+        &[&seeds[..]],
+    ))?;
+
+    // Hand the token account rent back to whoever paid it on wrap
+    **ctx.accounts.escrow.to_account_info().try_borrow_mut_lamports()? -= wsol_rent;
+    **ctx.accounts.rent_payer.to_account_info().try_borrow_mut_lamports()? += wsol_rent;
+    ctx.accounts.escrow.wsol_rent_payer = None;
+
+    msg!("Unwrapped {} lamports of wSOL back into escrow", amount);
+
+    emit!(EscrowUnwrappedEvent {
+        schema_version: EVENT_SCHEMA_VERSION,
+        pool: ctx.accounts.pool.key(),
+        nft_mint: nft_mint_key,
+        amount,
+    });
+
+    Ok(())
+}
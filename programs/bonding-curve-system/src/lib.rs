@@ -4,16 +4,34 @@ declare_id!("BYBbjAurgYTyexC2RrbTZKMDDdG7JHha1p3RsZpZCqba"); // Replace with you
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod math;
 pub mod state;
+pub mod utils;
 
 // Re-export instruction contexts
+use instructions::close_pool::*;
 use instructions::create_collection_nft::*;
 use instructions::create_pool::*;
+use instructions::emergency_pause::*;
+use instructions::initialize_audit_log::*;
+use instructions::initialize_collection_stats::*;
+use instructions::migrate_pool_account::*;
+use instructions::migrate_escrow_account::*;
 use instructions::migrate_to_tensor::*;
 use instructions::mint_nft::*;
+use instructions::quote_mint::*;
+use instructions::quote_sell::*;
 use instructions::sell_nft::*; // <-- Added import for the new instruction
+use instructions::transfer_nft::*;
+use instructions::transfer_pool_authority::*;
+use instructions::update_pool_config::*;
+use instructions::verify_collection_item::*;
+use instructions::view_pool_stats::*;
+use instructions::view_price_history::*;
+use instructions::view_supply_for_price::*;
+use instructions::wrap_escrow::*;
 
 #[program]
 pub mod bonding_curve_system {
@@ -45,17 +63,128 @@ pub mod bonding_curve_system {
         symbol: String,
         uri: String,
         seller_fee_basis_points: u16,
+        max_price: u64, // Highest acceptable mint price in lamports, 0 = no limit
     ) -> Result<()> {
-        instructions::mint_nft::mint_nft(ctx, name, symbol, uri, seller_fee_basis_points)
+        instructions::mint_nft::mint_nft(ctx, name, symbol, uri, seller_fee_basis_points, max_price)
     }
 
     // Sells (burns) an NFT, returning SOL from its escrow
-    pub fn sell_nft(ctx: Context<SellNFT>) -> Result<()> {
-        instructions::sell_nft::sell_nft(ctx)
+    pub fn sell_nft(
+        ctx: Context<SellNFT>,
+        min_proceeds: u64, // Lowest acceptable net payout in lamports, 0 = no floor
+    ) -> Result<()> {
+        instructions::sell_nft::sell_nft(ctx, min_proceeds)
     }
 
     // Migrates the pool to Tensor (freezes the pool)
     pub fn migrate_to_tensor(ctx: Context<MigrateToTensor>) -> Result<()> {
         instructions::migrate_to_tensor::migrate_to_tensor(ctx)
     }
+
+    // Pauses the selected operations, optionally lifting itself at auto_unpause_at
+    pub fn emergency_pause(
+        ctx: Context<EmergencyPause>,
+        paused_operations: u8, // Bitmask of PauseState::{MINT, BUY (reserved), SELL}
+        reason: String,
+        auto_unpause_at: i64, // Unix timestamp, 0 = manual unpause only
+    ) -> Result<()> {
+        instructions::emergency_pause::emergency_pause(ctx, paused_operations, reason, auto_unpause_at)
+    }
+
+    // Lifts an emergency pause before it expires
+    pub fn unpause_pool(ctx: Context<EmergencyPause>) -> Result<()> {
+        instructions::emergency_pause::unpause_pool(ctx)
+    }
+
+    // Proposes a new pool creator; takes effect once they accept
+    pub fn transfer_pool_authority(
+        ctx: Context<TransferPoolAuthority>,
+        new_creator: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_pool_authority::transfer_pool_authority(ctx, new_creator)
+    }
+
+    // Completes a pool authority handoff, signed by the pending creator
+    pub fn accept_pool_authority(ctx: Context<AcceptPoolAuthority>) -> Result<()> {
+        instructions::transfer_pool_authority::accept_pool_authority(ctx)
+    }
+
+    // Emits the supply at which the mint price first reaches target_price
+    pub fn view_supply_for_price(ctx: Context<ViewSupplyForPrice>, target_price: u64) -> Result<()> {
+        instructions::view_supply_for_price::view_supply_for_price(ctx, target_price)
+    }
+
+    // Creates the optional on-chain audit trail that mint/sell append to
+    pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+        instructions::initialize_audit_log::initialize_audit_log(ctx)
+    }
+
+    // Closes an empty pool, sweeping its lamports to the creator
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        instructions::close_pool::close_pool(ctx)
+    }
+
+    // Moves an NFT escrow's spare lamports into a wSOL account it owns (migrated pools only)
+    pub fn wrap_escrow(ctx: Context<WrapEscrow>) -> Result<()> {
+        instructions::wrap_escrow::wrap_escrow(ctx)
+    }
+
+    // Returns a wrapped escrow to plain lamports, refunding the wSOL account rent
+    pub fn unwrap_escrow(ctx: Context<UnwrapEscrow>) -> Result<()> {
+        instructions::wrap_escrow::unwrap_escrow(ctx)
+    }
+
+    // Updates creator-tunable pool settings; None leaves a setting unchanged
+    pub fn update_pool_config(
+        ctx: Context<UpdatePoolConfig>,
+        args: UpdatePoolConfigArgs,
+    ) -> Result<()> {
+        instructions::update_pool_config::update_pool_config(ctx, args)
+    }
+
+    // Emits what selling an NFT would pay out right now
+    pub fn quote_sell(ctx: Context<QuoteSell>) -> Result<()> {
+        instructions::quote_sell::quote_sell(ctx)
+    }
+
+    // Emits the current mint price and how it splits between fee and escrow
+    pub fn quote_mint(ctx: Context<QuoteMint>) -> Result<()> {
+        instructions::quote_mint::quote_mint(ctx)
+    }
+
+    // Emits the pool's recent executed prices, oldest first
+    pub fn view_price_history(ctx: Context<ViewPriceHistory>) -> Result<()> {
+        instructions::view_price_history::view_price_history(ctx)
+    }
+
+    // Moves a pool NFT to another wallet; its escrow stays attached to the mint
+    pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
+        instructions::transfer_nft::transfer_nft(ctx)
+    }
+
+    // Creates the optional lifetime volume/fee totals that mint/sell update
+    pub fn initialize_collection_stats(ctx: Context<InitializeCollectionStats>) -> Result<()> {
+        instructions::initialize_collection_stats::initialize_collection_stats(ctx)
+    }
+
+    // Verifies a minted NFT as a member of the pool's collection (signed by its update authority)
+    pub fn verify_collection_item(ctx: Context<VerifyCollectionItem>) -> Result<()> {
+        instructions::verify_collection_item::verify_collection_item(ctx)
+    }
+
+    // Emits supply, escrow, market cap and how much of it escrow backs
+    pub fn view_pool_stats(ctx: Context<ViewPoolStats>) -> Result<()> {
+        instructions::view_pool_stats::view_pool_stats(ctx)
+    }
+
+    // Rewrites a pool created before layout versioning into the current account layout
+    pub fn migrate_pool_account(ctx: Context<MigratePoolAccount>) -> Result<()> {
+        instructions::migrate_pool_account::migrate_pool_account(ctx)
+    }
+
+    // Rewrites an escrow from before edition numbers into the current layout,
+    // binding it to the pool its NFT's metadata names
+    pub fn migrate_escrow_account(ctx: Context<MigrateEscrowAccount>) -> Result<()> {
+        instructions::migrate_escrow_account::migrate_escrow_account(ctx)
+    }
 }
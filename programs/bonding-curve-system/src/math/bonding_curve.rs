@@ -15,21 +15,12 @@ impl BondingCurve {
             return Ok(self.base_price);
         }
         
-        // Validate inputs to prevent overflow
-        if self.base_price > u64::MAX / 1_000_000 {
-            return Err(error!(crate::errors::ErrorCode::MathOverflow));
-        }
-        
         // Calculate e^(growth_factor * current_market_cap)
         // Using Taylor series approximation for efficiency
         let exponent = self.calculate_exponent(current_market_cap)?;
         
         // Calculate final price: base_price * e^(growth_factor * current_market_cap)
-        self.base_price
-            .checked_mul(exponent)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
-            .checked_div(1_000_000) // Scaling factor for fixed-point math
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))
+        checked_mul_div(self.base_price, exponent, CURVE_SCALING_FACTOR)
     }
     
     // Calculate total cost to buy a specific amount of tokens
@@ -109,11 +100,6 @@ impl BondingCurve {
     
     // Calculate mint fee (1% of total cost)
     pub fn calculate_mint_fee(&self, total_cost: u64) -> Result<u64> {
-        // Check for potential overflow before multiplying
-        if total_cost > u64::MAX / MINT_FEE_PERCENTAGE {
-            return Err(error!(crate::errors::ErrorCode::MathOverflow));
-        }
-        
         total_cost
             .checked_mul(MINT_FEE_PERCENTAGE)
             .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
@@ -209,19 +195,10 @@ impl BondingCurve {
     // e^x ≈ 1 + x + x²/2! + x³/3! + x⁴/4! + ...
     fn calculate_exponent(&self, current_market_cap: u64) -> Result<u64> {
         // Scale down for fixed-point math (6 decimal places)
-        let scaling_factor = 1_000_000;
-        
-        // Validate inputs to prevent overflow
-        if self.growth_factor > u64::MAX / current_market_cap {
-            return Err(error!(crate::errors::ErrorCode::MathOverflow));
-        }
+        let scaling_factor = CURVE_SCALING_FACTOR;
         
         // Calculate x = growth_factor * current_market_cap / scaling_factor
-        let x = self.growth_factor
-            .checked_mul(current_market_cap)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
-            .checked_div(scaling_factor)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?;
+        let x = checked_mul_div(self.growth_factor, current_market_cap, scaling_factor)?;
         
         // Limit x to prevent excessive calculations and potential overflows
         if x > 100 * scaling_factor {
@@ -275,14 +252,12 @@ impl BondingCurve {
         }
         
         // Fourth term: (x³/3!) * scaling_factor
-        // Check for potential overflow before multiplying
-        if x > u64::MAX / (x * x) {
-            return Err(error!(crate::errors::ErrorCode::MathOverflow));
-        }
-        
-        let term3 = x
+        // x² is checked too, so a large x reports MathOverflow instead of panicking
+        let x_squared = x
             .checked_mul(x)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
+            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?;
+        
+        let term3 = x_squared
             .checked_mul(x)
             .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
             .checked_div(6) // 3! = 6
@@ -303,17 +278,8 @@ impl BondingCurve {
         }
         
         // Fifth term: (x⁴/4!) * scaling_factor
-        // Check for potential overflow before multiplying
-        if x > u64::MAX / (x * x * x) {
-            return Err(error!(crate::errors::ErrorCode::MathOverflow));
-        }
-        
-        let term4 = x
-            .checked_mul(x)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
-            .checked_mul(x)
-            .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
-            .checked_mul(x)
+        let term4 = x_squared
+            .checked_mul(x_squared)
             .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?
             .checked_div(24) // 4! = 24
             .ok_or(error!(crate::errors::ErrorCode::MathOverflow))?;
@@ -330,3 +296,121 @@ impl BondingCurve {
         Ok(result)
     }
 }
+
+// Calculate value * multiplier / divisor with a u128 intermediate, so large
+// amounts only fail when the scaled result itself does not fit in a u64
+pub fn checked_mul_div(value: u64, multiplier: u64, divisor: u64) -> Result<u64> {
+    if divisor == 0 {
+        return Err(error!(crate::errors::ErrorCode::MathOverflow));
+    }
+    
+    let result = (value as u128) * (multiplier as u128) / (divisor as u128);
+    
+    u64::try_from(result).map_err(|_| error!(crate::errors::ErrorCode::MathOverflow))
+}
+
+// Calculate base * (factor / scale)^exp with a u128 accumulator, rounding down
+// after every step so repeated callers walk exactly the same curve
+pub fn checked_pow_fixed(base: u64, factor: u64, scale: u64, exp: u64) -> Result<u64> {
+    if scale == 0 {
+        return Err(error!(crate::errors::ErrorCode::MathOverflow));
+    }
+    
+    let mut value = base as u128;
+    
+    for _ in 0..exp {
+        value = value * factor as u128 / scale as u128;
+        
+        if value > u64::MAX as u128 {
+            return Err(error!(crate::errors::ErrorCode::MathOverflow));
+        }
+    }
+    
+    Ok(value as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(growth_factor: u64) -> BondingCurve {
+        BondingCurve {
+            base_price: 1_000_000,
+            growth_factor,
+        }
+    }
+
+    #[test]
+    fn calculate_price_returns_base_price_for_empty_market() {
+        assert_eq!(curve(1).calculate_price(0).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn calculate_price_stays_checked_for_large_market_cap() {
+        // x = 10 * 50_000_000 / 1_000_000 = 500, so all five Taylor terms run
+        let price = curve(10).calculate_price(50_000_000).unwrap();
+        let exponent = 1_000_000 + 500 + 500 * 500 / 2 + 500 * 500 * 500 / 6
+            + 500 * 500 * 500 * 500 / 24;
+        assert_eq!(price, 1_000_000 * exponent / CURVE_SCALING_FACTOR);
+    }
+
+    #[test]
+    fn calculate_price_reports_overflow_when_cube_overflows() {
+        // x = 3_000_000: x² fits in a u64 but x³ does not
+        let err = curve(CURVE_SCALING_FACTOR)
+            .calculate_price(3_000_000)
+            .unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn calculate_price_reports_overflow_when_fourth_power_overflows() {
+        // x = 100_000: x³ fits in a u64 but x⁴ does not
+        let err = curve(CURVE_SCALING_FACTOR)
+            .calculate_price(100_000)
+            .unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn calculate_price_reports_overflow_near_exponent_limit() {
+        // x = 100 * CURVE_SCALING_FACTOR is the largest accepted exponent
+        let err = curve(CURVE_SCALING_FACTOR)
+            .calculate_price(100 * CURVE_SCALING_FACTOR)
+            .unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn calculate_price_reports_overflow_for_max_market_cap() {
+        let err = curve(u64::MAX).calculate_price(u64::MAX).unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn checked_pow_fixed_with_zero_exponent_returns_base() {
+        assert_eq!(checked_pow_fixed(123, 1_200_000, 1_000_000, 0).unwrap(), 123);
+    }
+
+    #[test]
+    fn checked_pow_fixed_rejects_zero_scale() {
+        let err = checked_pow_fixed(1, 1, 0, 1).unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn checked_pow_fixed_reports_overflow() {
+        let err = checked_pow_fixed(u64::MAX, 2_000_000, 1_000_000, 1).unwrap_err();
+        assert_eq!(err, error!(crate::errors::ErrorCode::MathOverflow));
+    }
+
+    #[test]
+    fn checked_pow_fixed_matches_stepwise_rounding() {
+        // One call with exp = n must land on the same value as n single steps
+        let mut stepped = 10_000_000;
+        for exp in 1..=40 {
+            stepped = checked_pow_fixed(stepped, 1_234_567, 1_000_000, 1).unwrap();
+            assert_eq!(checked_pow_fixed(10_000_000, 1_234_567, 1_000_000, exp).unwrap(), stepped);
+        }
+    }
+}
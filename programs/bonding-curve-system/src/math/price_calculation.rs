@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::constants::{MAX_SUPPLY_SEARCH_STEPS, SELL_FEE_PERCENTAGE};
 use crate::errors::ErrorCode;
+use crate::math::bonding_curve::{checked_mul_div, checked_pow_fixed};
+
+// Fixed-point arithmetic with 6 decimal places
+// growth_factor of 1.2 is represented as 1_200_000
+const FIXED_POINT_SCALE: u64 = 1_000_000;
 
 // Calculate price for minting an NFT
 // price = base_price * growth_factor^current_supply
@@ -8,28 +14,10 @@ pub fn calculate_mint_price(
     growth_factor: u64,
     current_supply: u64,
 ) -> Result<u64> {
-    // Fixed-point arithmetic with 6 decimal places
-    // growth_factor of 1.2 is represented as 1_200_000
-    const FIXED_POINT_SCALE: u64 = 1_000_000;
-    
-    // For the first NFT (supply = 0), price is just the base price
-    if current_supply == 0 {
-        return Ok(base_price);
-    }
-    
-    // For subsequent NFTs, apply the growth factor
-    // We use a simple multiplication approach for fixed-point math
-    let mut price = base_price;
-    
-    for _ in 0..current_supply {
-        price = price
-            .checked_mul(growth_factor)
-            .ok_or(ErrorCode::MathOverflow)?
-            .checked_div(FIXED_POINT_SCALE)
-            .ok_or(ErrorCode::MathOverflow)?;
-    }
-    
-    Ok(price)
+    // Each step multiplies in u128 so only a price that no longer fits in
+    // a u64 is rejected, never an intermediate product
+    checked_pow_fixed(base_price, growth_factor, FIXED_POINT_SCALE, current_supply)
+        .map_err(|_| ErrorCode::PriceCalculationOverflow.into())
 }
 
 // Calculate price for selling an NFT
@@ -48,3 +36,137 @@ pub fn calculate_sell_price(
     // So we calculate for (current_supply - 1)
     calculate_mint_price(base_price, growth_factor, current_supply - 1)
 }
+
+// Calculate the smallest supply at which the mint price reaches target_price
+// Walks the curve one step at a time, the same way calculate_mint_price does,
+// so the answer always round-trips through calculate_mint_price. Bisecting
+// would not help: calculate_mint_price is itself a stepwise product, so each
+// probe costs as much as the walk. The walk stops at MAX_SUPPLY_SEARCH_STEPS
+pub fn calculate_supply_for_price(
+    base_price: u64,
+    growth_factor: u64,
+    target_price: u64,
+) -> Result<u64> {
+    let mut price = base_price;
+    let mut supply: u64 = 0;
+    
+    while price < target_price {
+        let next_price = checked_pow_fixed(price, growth_factor, FIXED_POINT_SCALE, 1)
+            .map_err(|_| error!(ErrorCode::PriceCalculationOverflow))?;
+        
+        // A flat or falling curve (or one stuck on rounding) never gets there
+        if next_price <= price {
+            return Err(ErrorCode::InvalidPrice.into());
+        }
+        
+        price = next_price;
+        supply = supply.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        
+        if supply > MAX_SUPPLY_SEARCH_STEPS {
+            return Err(ErrorCode::SupplySearchLimitExceeded.into());
+        }
+    }
+    
+    Ok(supply)
+}
+
+// Split a mint price into the platform fee (the pool's mint_fee_bp, paid to
+// PLATFORM_WALLET) and the amount escrowed behind the new NFT
+// Returns (platform_fee, escrow_amount)
+pub fn calculate_mint_split(price: u64, mint_fee_bp: u16) -> Result<(u64, u64)> {
+    let platform_fee = checked_mul_div(price, mint_fee_bp as u64, 10_000)?;
+    
+    let escrow_amount = price.checked_sub(platform_fee).ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok((platform_fee, escrow_amount))
+}
+
+// Split an escrow's balance above its rent-exempt minimum into the creator's
+// sell fee and the seller's net payout
+// Returns (gross, fee, net_to_seller)
+pub fn calculate_sell_proceeds(
+    escrow_lamports: u64,
+    rent_exempt_minimum: u64,
+) -> Result<(u64, u64, u64)> {
+    let gross = escrow_lamports.saturating_sub(rent_exempt_minimum);
+    
+    let fee = gross
+        .checked_mul(SELL_FEE_PERCENTAGE)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(100)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    let net_to_seller = gross.checked_sub(fee).ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok((gross, fee, net_to_seller))
+}
+
+// Market cap of a pool: every NFT valued at the most recent mint price
+pub fn calculate_market_cap(
+    base_price: u64,
+    growth_factor: u64,
+    current_supply: u64,
+) -> Result<u64> {
+    if current_supply == 0 {
+        return Ok(0);
+    }
+    
+    let last_price = calculate_sell_price(base_price, growth_factor, current_supply)?;
+    let market_cap = last_price
+        .checked_mul(current_supply)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok(market_cap)
+}
+
+// Share of the market cap covered by escrowed SOL, in basis points capped at
+// 10000; an empty pool (no market cap) reports 0
+pub fn calculate_backing_ratio_bp(total_escrowed: u64, market_cap: u64) -> u16 {
+    if market_cap == 0 {
+        return 0;
+    }
+    
+    let ratio = total_escrowed as u128 * 10_000 / market_cap as u128;
+    ratio.min(10_000) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculate_supply_for_price_round_trips_through_mint_price() {
+        let (base_price, growth_factor) = (10_000_000, 1_200_000);
+        
+        for target_price in [10_000_000, 10_000_001, 14_400_000, 1_000_000_000] {
+            let supply = calculate_supply_for_price(base_price, growth_factor, target_price).unwrap();
+            
+            assert!(calculate_mint_price(base_price, growth_factor, supply).unwrap() >= target_price);
+            if supply > 0 {
+                assert!(calculate_mint_price(base_price, growth_factor, supply - 1).unwrap() < target_price);
+            }
+        }
+    }
+
+    #[test]
+    fn calculate_supply_for_price_rejects_flat_curve() {
+        let err = calculate_supply_for_price(10_000_000, 1_000_000, 20_000_000).unwrap_err();
+        assert_eq!(err, error!(ErrorCode::InvalidPrice));
+    }
+
+    #[test]
+    fn calculate_supply_for_price_stops_at_the_step_limit_on_a_slow_curve() {
+        // At 1.000001x per mint a 0.001 SOL base price climbs by 1 lamport per step
+        let (base_price, growth_factor) = (1_000_000, 1_000_001);
+        
+        let supply =
+            calculate_supply_for_price(base_price, growth_factor, base_price + MAX_SUPPLY_SEARCH_STEPS)
+                .unwrap();
+        assert_eq!(supply, MAX_SUPPLY_SEARCH_STEPS);
+        
+        for target_price in [base_price + MAX_SUPPLY_SEARCH_STEPS + 1, 2 * base_price] {
+            let err = calculate_supply_for_price(base_price, growth_factor, target_price).unwrap_err();
+            assert_eq!(err, error!(ErrorCode::SupplySearchLimitExceeded));
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    Mint,
+    Sell,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditEntry {
+    pub action: AuditAction,         // What happened
+    pub actor: Pubkey,               // Signer that performed the action
+    pub amount: u64,                 // Lamports involved (mint price / seller proceeds)
+    pub slot: u64,                   // Slot the action landed in
+}
+
+impl AuditEntry {
+    // 1 (action) + 32 (actor) + 8 (amount) + 8 (slot)
+    pub const SPACE: usize = 1 + 32 + 8 + 8;
+}
+
+// Append-only ring of the most recent pool actions, kept on-chain so the
+// trail survives even if an indexer misses the emitted events
+#[account]
+pub struct AuditLog {
+    pub pool: Pubkey,                // Pool this log belongs to
+    pub next_index: u16,             // Position the next entry is written to
+    pub total_entries: u64,          // Entries ever recorded (including overwritten ones)
+    pub entries: Vec<AuditEntry>,    // Up to CAPACITY entries, oldest overwritten first
+    pub bump: u8,                    // PDA bump
+}
+
+impl AuditLog {
+    pub const CAPACITY: usize = 32;
+
+    // 8 (discriminator) + 32 (pool) + 2 (next_index) + 8 (total_entries) +
+    // 4 + CAPACITY * AuditEntry::SPACE (entries) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 2 + 8 + 4 + Self::CAPACITY * AuditEntry::SPACE + 1;
+
+    pub fn record(&mut self, action: AuditAction, actor: Pubkey, amount: u64, slot: u64) -> Result<()> {
+        let entry = AuditEntry {
+            action,
+            actor,
+            amount,
+            slot,
+        };
+
+        let index = self.next_index as usize;
+        if self.entries.len() < Self::CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[index] = entry;
+        }
+
+        self.next_index = ((index + 1) % Self::CAPACITY) as u16;
+        self.total_entries = self
+            .total_entries
+            .checked_add(1)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+// Lifetime totals for a collection's pool, so clients don't have to replay
+// every mint and sell event to show volume and fees
+#[account]
+pub struct CollectionStats {
+    pub collection: Pubkey,          // Collection mint these totals belong to
+    pub total_volume: u64,           // Lamports traded: mint prices plus sell payouts (seller + fee)
+    pub total_sales: u64,            // Number of sells
+    pub total_platform_fees: u64,    // Mint fees (pool mint_fee_bp) paid to the platform wallet
+    pub total_collection_fees: u64,  // Sell fees (SELL_FEE_PERCENTAGE) paid to the creator
+    pub bump: u8,                    // PDA bump
+}
+
+impl CollectionStats {
+    // 8 (discriminator) + 32 (collection) + 8 (total_volume) + 8 (total_sales) +
+    // 8 (total_platform_fees) + 8 (total_collection_fees) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    pub fn record_sale(&mut self, volume: u64, collection_fee: u64) -> Result<()> {
+        self.total_volume = self
+            .total_volume
+            .checked_add(volume)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        self.total_sales = self
+            .total_sales
+            .checked_add(1)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        self.total_collection_fees = self
+            .total_collection_fees
+            .checked_add(collection_fee)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_mint(&mut self, price: u64, platform_fee: u64) -> Result<()> {
+        self.total_volume = self
+            .total_volume
+            .checked_add(price)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        self.total_platform_fees = self
+            .total_platform_fees
+            .checked_add(platform_fee)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        Ok(())
+    }
+}
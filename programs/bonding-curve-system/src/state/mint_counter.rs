@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+// Per-wallet mint tracking for a pool, used to space out mints from the same
+// wallet when the pool has a mint cooldown
+#[account]
+pub struct MintCounter {
+    pub pool: Pubkey,                // Pool the mints were made from
+    pub minter: Pubkey,              // Wallet that paid for the mints
+    pub mint_count: u64,             // Mints this wallet has made from the pool
+    pub last_mint_ts: i64,           // Timestamp of the most recent mint
+    pub bump: u8,                    // PDA bump
+}
+
+impl MintCounter {
+    // 8 (discriminator) + 32 (pool) + 32 (minter) + 8 (mint_count) +
+    // 8 (last_mint_ts) + 1 (bump)
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1;
+
+    // The first mint is never held back; later ones wait out the cooldown
+    pub fn is_cooling_down(&self, cooldown_seconds: i64, now: i64) -> bool {
+        self.mint_count > 0
+            && cooldown_seconds > 0
+            && now.saturating_sub(self.last_mint_ts) < cooldown_seconds
+    }
+
+    pub fn record_mint(&mut self, now: i64) -> Result<()> {
+        self.mint_count = self
+            .mint_count
+            .checked_add(1)
+            .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+        self.last_mint_ts = now;
+        Ok(())
+    }
+}
@@ -3,11 +3,17 @@ use anchor_lang::prelude::*;
 pub mod pool;
 pub mod nft;
 pub mod nft_escrow;
+pub mod audit_log;
+pub mod collection_stats;
+pub mod mint_counter;
 
 pub use pool::*;
 pub use nft::*;
+pub use audit_log::*;
+pub use collection_stats::*;
+pub use mint_counter::*;
 // Use explicit imports instead of glob imports to avoid ambiguity
-pub use nft_escrow::NftEscrow;
+pub use nft_escrow::{NftEscrow, NftEscrowV1};
 
 // Add missing UserAccount struct
 #[account]
@@ -6,11 +6,82 @@ pub struct NftEscrow {
     pub lamports: u64,               // Escrowed SOL value
     pub last_price: u64,             // Price at last action
     pub bump: u8,                    // PDA bump
+    pub edition_number: u64,         // 1-based mint order; restarts if the pool is closed and re-created
+    pub minted_at: i64,              // Timestamp of the mint, for the pool's min_hold_seconds
+    pub pool: Pubkey,                // Pool that minted the NFT and owns this escrow
+    pub wsol_rent_payer: Option<Pubkey>, // Paid the open wSOL account's rent (wrap_escrow); refunded on unwrap
 }
 
 impl NftEscrow {
     // Define the space required for the NftEscrow account
+    // 8 (discriminator) + 32 (nft_mint) + 8 (lamports) + 8 (last_price) + 1 (bump) +
+    // 8 (edition_number) + 8 (minted_at) + 32 (pool) + 1 + 32 (wsol_rent_payer)
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 1 + 8 + 8 + 32 + 1 + 32;
+}
+
+// Layout v1: the escrow as originally deployed, before edition_number, minted_at
+// and pool were appended. Only read by migrate_escrow_account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct NftEscrowV1 {
+    pub nft_mint: Pubkey,
+    pub lamports: u64,
+    pub last_price: u64,
+    pub bump: u8,
+}
+
+impl NftEscrowV1 {
     // 8 (discriminator) + 32 (nft_mint) + 8 (lamports) + 8 (last_price) + 1 (bump)
     pub const SPACE: usize = 8 + 32 + 8 + 8 + 1;
+
+    // v1 recorded neither the mint order nor the mint time, so both stay 0:
+    // the NFT has no edition number and is outside any min_hold_seconds window
+    pub fn into_current(self, pool: Pubkey) -> NftEscrow {
+        NftEscrow {
+            nft_mint: self.nft_mint,
+            lamports: self.lamports,
+            last_price: self.last_price,
+            bump: self.bump,
+            edition_number: 0,
+            minted_at: 0,
+            pool,
+            wsol_rent_payer: None,
+        }
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v1_space_matches_its_serialized_size() {
+        let data = NftEscrowV1::default().try_to_vec().unwrap();
+        assert_eq!(data.len(), NftEscrowV1::SPACE - 8);
+    }
+
+    #[test]
+    fn into_current_keeps_v1_fields_and_binds_the_pool() {
+        let pool = Pubkey::new_unique();
+        let v1 = NftEscrowV1 {
+            nft_mint: Pubkey::new_unique(),
+            lamports: 10_000_000,
+            last_price: 10_100_000,
+            bump: 253,
+        };
+        let escrow = v1.clone().into_current(pool);
+
+        assert_eq!(escrow.nft_mint, v1.nft_mint);
+        assert_eq!(escrow.lamports, 10_000_000);
+        assert_eq!(escrow.last_price, 10_100_000);
+        assert_eq!(escrow.bump, 253);
+        assert_eq!(escrow.edition_number, 0);
+        assert_eq!(escrow.minted_at, 0);
+        assert_eq!(escrow.pool, pool);
+        assert_eq!(escrow.wsol_rent_payer, None);
+
+        // SPACE leaves room for a recorded wSOL rent payer
+        let mut wrapped = escrow.clone();
+        wrapped.wsol_rent_payer = Some(Pubkey::new_unique());
+        assert_eq!(wrapped.try_to_vec().unwrap().len(), NftEscrow::SPACE - 8);
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
+#[derive(Default)]
 pub struct BondingCurvePool {
     // --- Fields from Document --- 
     pub collection: Pubkey,          // Metaplex collection ID
@@ -27,6 +28,126 @@ pub struct BondingCurvePool {
     
     // --- PDA Bump ---
     pub bump: u8,                    // PDA bump for the pool account itself
+
+    // --- Emergency Pause ---
+    pub pause_state: Option<PauseState>, // Set while the pool is paused by the creator
+
+    // --- Authority Handoff ---
+    pub pending_creator: Option<Pubkey>, // Proposed creator awaiting accept_pool_authority
+
+    // --- Pool Config ---
+    pub min_supply_for_migration: u64, // NFTs that must be minted before migrating (0 = none)
+
+    // --- Price History ---
+    pub price_history: [u64; 24],    // Ring buffer of executed prices
+    pub price_history_ts: [i64; 24], // Timestamp of each entry (0 = slot never written)
+    pub price_history_idx: u8,       // Slot the next price is written to
+
+    pub mint_fee_bp: u16,            // Share of each mint price paid to PLATFORM_WALLET (<= MAX_MINT_FEE_BP)
+    pub reference_price: u64,        // Creator-set USD cents per SOL for event estimates (0 = unset)
+    pub total_minted: u64,           // NFTs ever minted; unlike current_supply, sells don't lower it
+
+    // --- Market Cap Cache ---
+    pub cached_market_cap: u64,      // calculate_market_cap at cached_at_supply
+    pub cached_at_supply: u64,       // current_supply the cache was computed for
+
+    pub mint_cooldown_seconds: i64,  // Minimum gap between mints from one wallet (0 = none)
+    pub min_hold_seconds: i64,       // Minimum time between minting an NFT and selling it (0 = none)
+
+    // --- Optional Companion Accounts ---
+    // Once created, mint and sell must pass them so no trade goes unrecorded
+    pub has_audit_log: bool,         // initialize_audit_log has run for this pool
+    pub has_collection_stats: bool,  // initialize_collection_stats has run for this pool
+
+    pub layout_version: u8,          // POOL_LAYOUT_VERSION this account was written with
+}
+
+// Layout v1: the pool as originally deployed, before any fields were appended.
+// Only read by migrate_pool_account, which rewrites it as the current layout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct BondingCurvePoolV1 {
+    pub collection: Pubkey,
+    pub base_price: u64,
+    pub growth_factor: u64,
+    pub current_supply: u64,
+    pub protocol_fee: u64,
+    pub creator: Pubkey,
+    pub total_escrowed: u64,
+    pub is_active: bool,
+    pub total_distributed: u64,
+    pub total_supply: u64,
+    pub current_market_cap: u64,
+    pub authority: Pubkey,
+    pub tensor_migration_timestamp: i64,
+    pub is_migrated_to_tensor: bool,
+    pub is_past_threshold: bool,
+    pub bump: u8,
+}
+
+impl BondingCurvePoolV1 {
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    // Same fields as the head of BondingCurvePool::SPACE, discriminator included
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 8 + 1 + 8 + 8 + 8 + 32 + 8 + 1 + 1 + 1;
+
+    // The v1 fields carried over as-is; everything appended since starts at its
+    // create_pool default, except the counters that can be derived from v1 state
+    pub fn into_current(self) -> BondingCurvePool {
+        let mut pool = BondingCurvePool {
+            collection: self.collection,
+            base_price: self.base_price,
+            growth_factor: self.growth_factor,
+            current_supply: self.current_supply,
+            protocol_fee: self.protocol_fee,
+            creator: self.creator,
+            total_escrowed: self.total_escrowed,
+            is_active: self.is_active,
+            total_distributed: self.total_distributed,
+            total_supply: self.total_supply,
+            current_market_cap: self.current_market_cap,
+            authority: self.authority,
+            tensor_migration_timestamp: self.tensor_migration_timestamp,
+            is_migrated_to_tensor: self.is_migrated_to_tensor,
+            is_past_threshold: self.is_past_threshold,
+            bump: self.bump,
+            mint_fee_bp: crate::constants::DEFAULT_MINT_FEE_BP,
+            // v1 kept no mint counter; edition numbers continue after the live supply
+            total_minted: self.current_supply,
+            layout_version: crate::constants::POOL_LAYOUT_VERSION,
+            ..Default::default()
+        };
+        pool.refresh_market_cap();
+        pool
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PauseState {
+    pub paused_by: Pubkey,           // Authority that paused the pool
+    pub reason: String,              // Human readable reason (max 128 chars)
+    pub paused_at: i64,              // Timestamp the pause started
+    pub auto_unpause_at: i64,        // Timestamp the pause lapses (0 = manual unpause only)
+    pub paused_operations: u8,       // Bitmask of PauseState::{MINT, BUY, SELL}
+}
+
+impl PauseState {
+    pub const MAX_REASON_LEN: usize = 128;
+
+    // Operation bits for paused_operations
+    pub const MINT: u8 = 1 << 0;
+    // Reserved: only buy_nft checks it, and buy_nft is not exposed in #[program],
+    // so setting it currently pauses nothing
+    pub const BUY: u8 = 1 << 1;
+    pub const SELL: u8 = 1 << 2;
+    pub const ALL: u8 = Self::MINT | Self::BUY | Self::SELL;
+
+    // 32 (paused_by) + 4 + 128 (reason) + 8 (paused_at) + 8 (auto_unpause_at) +
+    // 1 (paused_operations)
+    pub const SPACE: usize = 32 + 4 + Self::MAX_REASON_LEN + 8 + 8 + 1;
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.auto_unpause_at != 0 && now >= self.auto_unpause_at
+    }
 }
 
 impl BondingCurvePool {
@@ -35,8 +156,25 @@ impl BondingCurvePool {
     // 8 (current_supply) + 8 (protocol_fee) + 32 (creator) + 8 (total_escrowed) + 
     // 1 (is_active) + 8 (total_distributed) + 8 (total_supply) + 8 (current_market_cap) +
     // 32 (authority) + 8 (tensor_migration_timestamp) + 1 (is_migrated_to_tensor) + 
-    // 1 (is_past_threshold) + 1 (bump)
-    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 8 + 1 + 8 + 8 + 8 + 32 + 8 + 1 + 1 + 1;
+    // 1 (is_past_threshold) + 1 (bump) + 1 + PauseState::SPACE (pause_state) +
+    // 1 + 32 (pending_creator) + 8 (min_supply_for_migration) +
+    // 8 * 24 (price_history) + 8 * 24 (price_history_ts) + 1 (price_history_idx) +
+    // 2 (mint_fee_bp) + 8 (reference_price) + 8 (total_minted) +
+    // 8 (cached_market_cap) + 8 (cached_at_supply) + 8 (mint_cooldown_seconds) +
+    // 8 (min_hold_seconds) + 1 (has_audit_log) + 1 (has_collection_stats) +
+    // 1 (layout_version)
+    pub const SPACE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 32 + 8 + 1 + 8 + 8 + 8 + 32 + 8 + 1 + 1 + 1
+        + 1 + PauseState::SPACE
+        + 1 + 32
+        + 8
+        + 8 * Self::PRICE_HISTORY_LEN + 8 * Self::PRICE_HISTORY_LEN + 1
+        + 2 + 8 + 8
+        + 8 + 8
+        + 8 + 8
+        + 1 + 1
+        + 1;
+
+    pub const PRICE_HISTORY_LEN: usize = 24;
     
     // Methods referenced in migrate_to_tensor.rs
     pub fn is_migrated_to_tensor(&self) -> bool {
@@ -55,4 +193,144 @@ impl BondingCurvePool {
     pub fn set_past_threshold(&mut self, value: bool) {
         self.is_past_threshold = value;
     }
+
+    // USD cents for a lamport amount at the creator's reference price,
+    // 0 when no reference price is set
+    pub fn usd_estimate(&self, lamports: u64) -> Result<u64> {
+        crate::math::bonding_curve::checked_mul_div(
+            lamports,
+            self.reference_price,
+            anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL,
+        )
+    }
+
+    // Market cap at the current supply, reusing the cache unless supply moved since
+    pub fn market_cap(&self) -> Result<u64> {
+        if self.cached_at_supply == self.current_supply {
+            return Ok(self.cached_market_cap);
+        }
+        crate::math::price_calculation::calculate_market_cap(
+            self.base_price,
+            self.growth_factor,
+            self.current_supply,
+        )
+    }
+
+    // Called after every supply change so later reads hit the cache. A cap that
+    // no longer fits in a u64 is left uncached rather than failing the trade, so
+    // market_cap() recomputes it and reports the overflow
+    pub fn refresh_market_cap(&mut self) {
+        if let Ok(market_cap) = self.market_cap() {
+            self.cached_market_cap = market_cap;
+            self.cached_at_supply = self.current_supply;
+        }
+    }
+
+    // Overwrites the oldest entry once all PRICE_HISTORY_LEN slots are used
+    pub fn record_price(&mut self, price: u64, timestamp: i64) {
+        let idx = self.price_history_idx as usize % Self::PRICE_HISTORY_LEN;
+        self.price_history[idx] = price;
+        self.price_history_ts[idx] = timestamp;
+        self.price_history_idx = ((idx + 1) % Self::PRICE_HISTORY_LEN) as u8;
+    }
+
+    // Recorded (timestamp, price) pairs, oldest first
+    pub fn price_history_chronological(&self) -> Vec<(i64, u64)> {
+        let start = self.price_history_idx as usize % Self::PRICE_HISTORY_LEN;
+        (0..Self::PRICE_HISTORY_LEN)
+            .map(|i| (start + i) % Self::PRICE_HISTORY_LEN)
+            .filter(|&idx| self.price_history_ts[idx] != 0)
+            .map(|idx| (self.price_history_ts[idx], self.price_history[idx]))
+            .collect()
+    }
+
+    // A pause stays in effect until it is lifted or its auto_unpause_at passes,
+    // and only blocks the operations whose bit is set in paused_operations
+    pub fn is_operation_paused(&self, operation: u8, now: i64) -> bool {
+        match &self.pause_state {
+            Some(pause) => pause.paused_operations & operation != 0 && !pause.is_expired(now),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(base_price: u64, growth_factor: u64) -> BondingCurvePool {
+        BondingCurvePool {
+            base_price,
+            growth_factor,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn refresh_market_cap_caches_the_computed_cap() {
+        let mut pool = pool(10_000_000, 1_200_000);
+        pool.current_supply = 2;
+        pool.refresh_market_cap();
+
+        assert_eq!(pool.cached_at_supply, 2);
+        assert_eq!(pool.cached_market_cap, 2 * 12_000_000);
+        assert_eq!(pool.market_cap().unwrap(), 2 * 12_000_000);
+    }
+
+    #[test]
+    fn refresh_market_cap_leaves_an_overflowing_cap_uncached() {
+        let mut pool = pool(u64::MAX / 2, 1_000_000);
+        pool.current_supply = 1;
+        pool.refresh_market_cap();
+        assert_eq!(pool.cached_market_cap, u64::MAX / 2);
+
+        // Two NFTs at u64::MAX / 2 each no longer fit in a u64
+        pool.current_supply = 3;
+        pool.refresh_market_cap();
+
+        assert_eq!(pool.cached_at_supply, 1);
+        assert_eq!(pool.cached_market_cap, u64::MAX / 2);
+        assert_eq!(
+            pool.market_cap().unwrap_err(),
+            error!(crate::errors::ErrorCode::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn v1_space_matches_its_serialized_size() {
+        let data = BondingCurvePoolV1::default().try_to_vec().unwrap();
+        assert_eq!(BondingCurvePoolV1::SPACE, 180);
+        assert_eq!(data.len(), BondingCurvePoolV1::SPACE - 8);
+    }
+
+    #[test]
+    fn into_current_keeps_v1_fields_and_defaults_the_rest() {
+        let creator = Pubkey::new_unique();
+        let v1 = BondingCurvePoolV1 {
+            collection: Pubkey::new_unique(),
+            base_price: 10_000_000,
+            growth_factor: 1_200_000,
+            current_supply: 2,
+            creator,
+            total_escrowed: 22_000_000,
+            is_active: true,
+            bump: 254,
+            ..Default::default()
+        };
+        let pool = v1.clone().into_current();
+
+        assert_eq!(pool.collection, v1.collection);
+        assert_eq!(pool.creator, creator);
+        assert_eq!(pool.current_supply, 2);
+        assert_eq!(pool.total_escrowed, 22_000_000);
+        assert!(pool.is_active);
+        assert_eq!(pool.bump, 254);
+        assert_eq!(pool.mint_fee_bp, crate::constants::DEFAULT_MINT_FEE_BP);
+        assert_eq!(pool.total_minted, 2);
+        assert_eq!(pool.layout_version, crate::constants::POOL_LAYOUT_VERSION);
+        assert_eq!(pool.pending_creator, None);
+        assert!(!pool.has_audit_log);
+        assert_eq!(pool.cached_at_supply, 2);
+        assert_eq!(pool.market_cap().unwrap(), 2 * 12_000_000);
+    }
 }
@@ -0,0 +1,3 @@
+pub mod pda;
+
+pub use pda::*;
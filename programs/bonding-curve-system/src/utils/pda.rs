@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    AUDIT_LOG_SEED, COLLECTION_STATS_SEED, MINT_COUNTER_SEED, NFT_ESCROW_SEED, POOL_SEED,
+};
+
+// Canonical PDA derivations. Account constraints use the same seed constants,
+// so clients calling these helpers always land on the addresses the program checks.
+
+// Bonding curve pool for a collection
+pub fn pool_pda(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_SEED, collection_mint.as_ref()], &crate::ID)
+}
+
+// SOL escrow backing a minted NFT
+pub fn escrow_pda(nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_ESCROW_SEED, nft_mint.as_ref()], &crate::ID)
+}
+
+// Optional on-chain audit trail for a pool
+pub fn audit_log_pda(pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUDIT_LOG_SEED, pool.as_ref()], &crate::ID)
+}
+
+// Optional lifetime totals for a collection
+pub fn collection_stats_pda(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COLLECTION_STATS_SEED, collection_mint.as_ref()], &crate::ID)
+}
+
+// Per-wallet mint counter for a pool
+pub fn mint_counter_pda(pool: &Pubkey, minter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_COUNTER_SEED, pool.as_ref(), minter.as_ref()], &crate::ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Literal seeds, so a renamed constant cannot silently move every PDA
+    fn derive(seeds: &[&[u8]]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(seeds, &crate::ID)
+    }
+
+    #[test]
+    fn pool_pda_matches_literal_seeds() {
+        let collection_mint = Pubkey::new_unique();
+        assert_eq!(
+            pool_pda(&collection_mint),
+            derive(&[b"bonding-curve-pool", collection_mint.as_ref()])
+        );
+    }
+
+    #[test]
+    fn escrow_pda_matches_literal_seeds() {
+        let nft_mint = Pubkey::new_unique();
+        assert_eq!(
+            escrow_pda(&nft_mint),
+            derive(&[b"nft-escrow", nft_mint.as_ref()])
+        );
+    }
+
+    #[test]
+    fn audit_log_pda_matches_literal_seeds() {
+        let pool = Pubkey::new_unique();
+        assert_eq!(audit_log_pda(&pool), derive(&[b"audit-log", pool.as_ref()]));
+    }
+
+    #[test]
+    fn collection_stats_pda_matches_literal_seeds() {
+        let collection_mint = Pubkey::new_unique();
+        assert_eq!(
+            collection_stats_pda(&collection_mint),
+            derive(&[b"collection-stats", collection_mint.as_ref()])
+        );
+    }
+
+    #[test]
+    fn mint_counter_pda_matches_literal_seeds() {
+        let pool = Pubkey::new_unique();
+        let minter = Pubkey::new_unique();
+        assert_eq!(
+            mint_counter_pda(&pool, &minter),
+            derive(&[b"mint-counter", pool.as_ref(), minter.as_ref()])
+        );
+    }
+
+    #[test]
+    fn mint_counter_pda_is_per_minter() {
+        let pool = Pubkey::new_unique();
+        assert_ne!(
+            mint_counter_pda(&pool, &Pubkey::new_unique()).0,
+            mint_counter_pda(&pool, &Pubkey::new_unique()).0
+        );
+    }
+}
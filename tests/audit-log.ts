@@ -0,0 +1,103 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  provider,
+  airdrop,
+  setupPool,
+  mintNft,
+  sellNft,
+  auditLogPda,
+  expectError,
+  BASE_PRICE,
+  PoolFixture,
+} from './helpers';
+
+const CAPACITY = 32;
+
+describe('audit-log', () => {
+  const user = Keypair.generate();
+
+  before(async () => {
+    await airdrop(user.publicKey, 20);
+  });
+
+  async function initAuditLog(fixture: PoolFixture) {
+    const auditLog = auditLogPda(fixture.pool);
+    await program.methods
+      .initializeAuditLog()
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+        auditLog,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([fixture.creator])
+      .rpc();
+    return auditLog;
+  }
+
+  it('Records the newest mint and sell', async () => {
+    const fixture = await setupPool();
+    const auditLog = await initAuditLog(fixture);
+
+    await mintNft(fixture, user, { auditLog });
+    const nftMint = await mintNft(fixture, user, { auditLog });
+    await sellNft(fixture, user, nftMint, { auditLog });
+
+    const log = await program.account.auditLog.fetch(auditLog);
+    assert.equal(log.totalEntries.toNumber(), 3);
+    assert.equal(log.entries.length, 3);
+    assert.deepEqual(log.entries[0].action, { mint: {} });
+    assert.equal(log.entries[0].amount.toNumber(), BASE_PRICE.toNumber());
+    assert.deepEqual(log.entries[2].action, { sell: {} });
+    assert.equal(log.entries[2].actor.toString(), user.publicKey.toString());
+    assert.isAtLeast(log.entries[2].slot.toNumber(), log.entries[1].slot.toNumber());
+
+    // Once the log exists, trades that leave it out are rejected
+    await expectError(mintNft(fixture, user), 'AuditLogRequired');
+    const heldMint = await mintNft(fixture, user, { auditLog });
+    await expectError(sellNft(fixture, user, heldMint), 'AuditLogRequired');
+  });
+
+  it('Wraps around and overwrites the oldest entry at capacity', async () => {
+    // A flat curve keeps every mint at the base price
+    const fixture = await setupPool(BASE_PRICE, new BN(1_000_000));
+    const auditLog = await initAuditLog(fixture);
+
+    for (let i = 0; i < CAPACITY + 1; i++) {
+      await mintNft(fixture, user, { auditLog });
+    }
+
+    const log = await program.account.auditLog.fetch(auditLog);
+    assert.equal(log.totalEntries.toNumber(), CAPACITY + 1);
+    assert.equal(log.entries.length, CAPACITY);
+    assert.equal(log.nextIndex, 1);
+    assert.isAtLeast(log.entries[0].slot.toNumber(), log.entries[CAPACITY - 1].slot.toNumber());
+  });
+
+  it('Closes the audit log together with its pool', async () => {
+    const fixture = await setupPool();
+    const auditLog = await initAuditLog(fixture);
+    const close = (log: PublicKey | null) =>
+      program.methods
+        .closePool()
+        .accounts({
+          authority: fixture.creator.publicKey,
+          pool: fixture.pool,
+          collectionMint: fixture.collectionMint,
+          auditLog: log,
+          collectionStats: null,
+        })
+        .signers([fixture.creator])
+        .rpc({ commitment: 'confirmed' });
+
+    await expectError(close(null), 'AuditLogRequired');
+    await close(auditLog);
+
+    assert.isNull(await provider.connection.getAccountInfo(fixture.pool, 'confirmed'));
+    assert.isNull(await provider.connection.getAccountInfo(auditLog, 'confirmed'));
+  });
+});
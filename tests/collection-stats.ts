@@ -0,0 +1,74 @@
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  airdrop,
+  setupPool,
+  mintNft,
+  mintNftTx,
+  sellNft,
+  collectionStatsPda,
+  fetchEvents,
+  findEvent,
+  expectError,
+  PoolFixture,
+} from './helpers';
+
+describe('collection-stats', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+  let collectionStats: PublicKey;
+
+  before(async () => {
+    await airdrop(user.publicKey);
+    fixture = await setupPool();
+    collectionStats = collectionStatsPda(fixture.collectionMint);
+
+    await program.methods
+      .initializeCollectionStats()
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+        collectionStats,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([fixture.creator])
+      .rpc();
+  });
+
+  it('Accumulates volume, sales and fees across three sales', async () => {
+    let volume = 0;
+    let platformFees = 0;
+    const nftMints: PublicKey[] = [];
+    for (let i = 0; i < 4; i++) {
+      const { nftMint, signature } = await mintNftTx(fixture, user, { collectionStats });
+      const minted = findEvent(await fetchEvents(signature), 'NftMint').data;
+      volume += minted.mintPrice.toNumber();
+      platformFees += minted.protocolFee.toNumber();
+      nftMints.push(nftMint);
+    }
+
+    // Sell newest first, keeping one NFT in the pool
+    let collectionFees = 0;
+    for (const nftMint of nftMints.slice(1).reverse()) {
+      const sale = findEvent(await fetchEvents(await sellNft(fixture, user, nftMint, { collectionStats })), 'NftSale');
+      volume += sale.data.salePrice.toNumber() + sale.data.sellFee.toNumber();
+      collectionFees += sale.data.sellFee.toNumber();
+    }
+
+    const stats = await program.account.collectionStats.fetch(collectionStats);
+    assert.equal(stats.collection.toString(), fixture.collectionMint.toString());
+    assert.equal(stats.totalSales.toNumber(), 3);
+    assert.equal(stats.totalVolume.toNumber(), volume);
+    assert.equal(stats.totalCollectionFees.toNumber(), collectionFees);
+    assert.equal(stats.totalPlatformFees.toNumber(), platformFees);
+  });
+
+  it('Requires the stats account on every trade once it exists', async () => {
+    await expectError(mintNft(fixture, user), 'CollectionStatsRequired');
+
+    const nftMint = await mintNft(fixture, user, { collectionStats });
+    await expectError(sellNft(fixture, user, nftMint), 'CollectionStatsRequired');
+  });
+});
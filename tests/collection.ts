@@ -0,0 +1,56 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  airdrop,
+  setupPool,
+  mintNft,
+  sellNft,
+  verifyAccounts,
+  fetchMetadataCollection,
+  expectError,
+  PoolFixture,
+} from './helpers';
+
+describe('collection', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+
+  before(async () => {
+    await airdrop(user.publicKey);
+    fixture = await setupPool();
+  });
+
+  it('Marks a minted NFT as a verified collection member', async () => {
+    const nftMint = await mintNft(fixture, user);
+
+    const minted = await fetchMetadataCollection(nftMint);
+    assert.equal(minted.key.toString(), fixture.collectionMint.toString());
+    assert.isFalse(minted.verified);
+
+    await program.methods
+      .verifyCollectionItem()
+      .accounts(verifyAccounts(fixture, fixture.creator, nftMint))
+      .signers([fixture.creator])
+      .rpc({ commitment: 'confirmed' });
+
+    const verified = await fetchMetadataCollection(nftMint);
+    assert.isTrue(verified.verified);
+  });
+
+  it('Rejects verification from someone other than the creator', async () => {
+    const nftMint = await mintNft(fixture, user);
+    await expectError(
+      program.methods.verifyCollectionItem().accounts(verifyAccounts(fixture, user, nftMint)).signers([user]).rpc(),
+      'InvalidAuthority'
+    );
+  });
+
+  it('Refuses to buy back an NFT whose collection is not verified', async () => {
+    const nftMint = await mintNft(fixture, user);
+    await expectError(sellNft(fixture, user, nftMint, {}, new BN(0), false), 'InvalidCollection');
+
+    await sellNft(fixture, user, nftMint);
+  });
+});
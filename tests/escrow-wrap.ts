@@ -0,0 +1,186 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  NATIVE_MINT,
+  getAssociatedTokenAddressSync,
+} from '@solana/spl-token';
+import { assert } from 'chai';
+import {
+  program,
+  provider,
+  airdrop,
+  setupPool,
+  mintNft,
+  migrateToTensor,
+  escrowPda,
+  expectError,
+  PoolFixture,
+} from './helpers';
+
+describe('escrow-wrap', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+  let nftMint: PublicKey;
+
+  function wrapAccounts(authority: PublicKey) {
+    const escrow = escrowPda(nftMint);
+    return {
+      authority,
+      pool: fixture.pool,
+      collectionMint: fixture.collectionMint,
+      nftMint,
+      escrow,
+      escrowWsol: getAssociatedTokenAddressSync(NATIVE_MINT, escrow, true),
+      nativeMint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  function unwrapAccounts(authority: PublicKey, rentPayer: PublicKey) {
+    const escrow = escrowPda(nftMint);
+    return {
+      authority,
+      pool: fixture.pool,
+      collectionMint: fixture.collectionMint,
+      nftMint,
+      escrow,
+      escrowWsol: getAssociatedTokenAddressSync(NATIVE_MINT, escrow, true),
+      rentPayer,
+      nativeMint: NATIVE_MINT,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  }
+
+  before(async () => {
+    await airdrop(user.publicKey, 800);
+
+    // A flat 100 SOL curve crosses the 690 SOL migration threshold after 7 mints
+    fixture = await setupPool(new BN(100 * LAMPORTS_PER_SOL), new BN(1_000_000));
+    nftMint = await mintNft(fixture, user);
+    for (let i = 0; i < 6; i++) {
+      await mintNft(fixture, user);
+    }
+  });
+
+  it('Refuses to wrap an escrow before the pool migrates', async () => {
+    await expectError(
+      program.methods
+        .wrapEscrow()
+        .accounts(wrapAccounts(fixture.creator.publicKey))
+        .signers([fixture.creator])
+        .rpc(),
+      'PoolNotMigrated'
+    );
+  });
+
+  it("Refuses to wrap another pool's escrow", async () => {
+    const escrow = await program.account.nftEscrow.fetch(escrowPda(nftMint));
+    assert.equal(escrow.pool.toString(), fixture.pool.toString());
+
+    const other = await setupPool();
+
+    await expectError(
+      program.methods
+        .wrapEscrow()
+        .accounts({
+          ...wrapAccounts(other.creator.publicKey),
+          pool: other.pool,
+          collectionMint: other.collectionMint,
+        })
+        .signers([other.creator])
+        .rpc(),
+      'InvalidPool'
+    );
+  });
+
+  it('Wraps and unwraps an escrow without losing lamports', async () => {
+    await migrateToTensor(fixture);
+
+    const accounts = wrapAccounts(fixture.creator.publicKey);
+    const escrowBefore = await provider.connection.getBalance(accounts.escrow, 'confirmed');
+    const rentMinimum = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(accounts.escrow)).data.length
+    );
+
+    await program.methods
+      .wrapEscrow()
+      .accounts(accounts)
+      .signers([fixture.creator])
+      .rpc({ commitment: 'confirmed' });
+
+    const wsol = await provider.connection.getTokenAccountBalance(accounts.escrowWsol, 'confirmed');
+    assert.equal(Number(wsol.value.amount), escrowBefore - rentMinimum);
+    assert.equal(await provider.connection.getBalance(accounts.escrow, 'confirmed'), rentMinimum);
+
+    const escrow = await program.account.nftEscrow.fetch(accounts.escrow);
+    assert.equal(escrow.wsolRentPayer.toString(), fixture.creator.publicKey.toString());
+
+    await program.methods
+      .unwrapEscrow()
+      .accounts(unwrapAccounts(fixture.creator.publicKey, fixture.creator.publicKey))
+      .signers([fixture.creator])
+      .rpc({ commitment: 'confirmed' });
+
+    assert.equal(await provider.connection.getBalance(accounts.escrow, 'confirmed'), escrowBefore);
+    assert.isNull(await provider.connection.getAccountInfo(accounts.escrowWsol, 'confirmed'));
+  });
+
+  it('Refunds the wSOL rent to whoever wrapped, even after the pool changes hands', async () => {
+    const wrapper = fixture.creator;
+    await program.methods
+      .wrapEscrow()
+      .accounts(wrapAccounts(wrapper.publicKey))
+      .signers([wrapper])
+      .rpc({ commitment: 'confirmed' });
+
+    const newCreator = Keypair.generate();
+    await airdrop(newCreator.publicKey, 1);
+    await program.methods
+      .transferPoolAuthority(newCreator.publicKey)
+      .accounts({
+        authority: wrapper.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([wrapper])
+      .rpc();
+    await program.methods
+      .acceptPoolAuthority()
+      .accounts({
+        newCreator: newCreator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([newCreator])
+      .rpc();
+
+    await expectError(
+      program.methods
+        .unwrapEscrow()
+        .accounts(unwrapAccounts(newCreator.publicKey, newCreator.publicKey))
+        .signers([newCreator])
+        .rpc(),
+      'InvalidRentPayer'
+    );
+
+    const escrowWsol = wrapAccounts(wrapper.publicKey).escrowWsol;
+    const wsolRent = await provider.connection.getMinimumBalanceForRentExemption(
+      (await provider.connection.getAccountInfo(escrowWsol)).data.length
+    );
+    const wrapperBefore = await provider.connection.getBalance(wrapper.publicKey, 'confirmed');
+
+    await program.methods
+      .unwrapEscrow()
+      .accounts(unwrapAccounts(newCreator.publicKey, wrapper.publicKey))
+      .signers([newCreator])
+      .rpc({ commitment: 'confirmed' });
+
+    assert.equal(await provider.connection.getBalance(wrapper.publicKey, 'confirmed'), wrapperBefore + wsolRent);
+    const escrow = await program.account.nftEscrow.fetch(escrowPda(nftMint));
+    assert.isNull(escrow.wsolRentPayer);
+  });
+});
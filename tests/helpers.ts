@@ -0,0 +1,323 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program, BN } from '@coral-xyz/anchor';
+import { BondingCurveSystem } from '../target/types/bonding_curve_system';
+import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { assert } from 'chai';
+
+// Shared fixtures for the NFT pool tests (collection -> pool -> mint/sell)
+
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
+export const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+
+export const program = anchor.workspace.BondingCurveSystem as Program<BondingCurveSystem>;
+
+// PLATFORM_WALLET in constants.rs; receives every pool's mint fees
+export const PLATFORM_WALLET = new PublicKey('c31WJz1oqhbjqoHnfMqoebVmZvZJ9BrPSqZPT26LwA7');
+
+export const BASE_PRICE = new BN(10_000_000); // 0.01 SOL
+export const GROWTH_FACTOR = new BN(1_200_000); // 1.2x per mint
+
+export interface PoolFixture {
+  creator: Keypair;
+  collectionMint: PublicKey;
+  pool: PublicKey;
+}
+
+export async function airdrop(to: PublicKey, sol = 10) {
+  const sig = await provider.connection.requestAirdrop(to, sol * LAMPORTS_PER_SOL);
+  await provider.connection.confirmTransaction(sig, 'confirmed');
+}
+
+export function poolPda(collectionMint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('bonding-curve-pool'), collectionMint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function escrowPda(nftMint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('nft-escrow'), nftMint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function auditLogPda(pool: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('audit-log'), pool.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function collectionStatsPda(collectionMint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('collection-stats'), collectionMint.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function mintCounterPda(pool: PublicKey, minter: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('mint-counter'), pool.toBuffer(), minter.toBuffer()],
+    program.programId
+  )[0];
+}
+
+export function metadataPda(mint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+}
+
+export function masterEditionPda(mint: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer(), Buffer.from('edition')],
+    TOKEN_METADATA_PROGRAM_ID
+  )[0];
+}
+
+export async function createCollection(payer: Keypair): Promise<PublicKey> {
+  const collectionMint = Keypair.generate();
+  await program.methods
+    .createCollectionNft('Test Collection', 'TCOL', 'https://example.com/collection.json')
+    .accounts({
+      payer: payer.publicKey,
+      collectionMint: collectionMint.publicKey,
+      metadataAccount: metadataPda(collectionMint.publicKey),
+      masterEditionAccount: masterEditionPda(collectionMint.publicKey),
+      tokenAccount: getAssociatedTokenAddressSync(collectionMint.publicKey, payer.publicKey),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([payer, collectionMint])
+    .rpc();
+  return collectionMint.publicKey;
+}
+
+export async function setupPool(basePrice = BASE_PRICE, growthFactor = GROWTH_FACTOR): Promise<PoolFixture> {
+  const creator = Keypair.generate();
+  await airdrop(creator.publicKey);
+
+  const collectionMint = await createCollection(creator);
+  const pool = poolPda(collectionMint);
+
+  // create_pool refuses a treasury below the rent-exempt minimum
+  if ((await provider.connection.getBalance(PLATFORM_WALLET)) === 0) {
+    await airdrop(PLATFORM_WALLET, 1);
+  }
+
+  await program.methods
+    .createPool(basePrice, growthFactor)
+    .accounts({
+      creator: creator.publicKey,
+      collectionMint,
+      pool,
+      platformWallet: PLATFORM_WALLET,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([creator])
+    .rpc();
+
+  return { creator, collectionMint, pool };
+}
+
+// update_pool_config arguments with every setting left unchanged except `settings`
+export function poolConfig(settings: {
+  minSupplyForMigration?: BN;
+  mintFeeBp?: number;
+  referencePrice?: BN;
+  mintCooldownSeconds?: BN;
+  minHoldSeconds?: BN;
+}) {
+  return {
+    minSupplyForMigration: null,
+    mintFeeBp: null,
+    referencePrice: null,
+    mintCooldownSeconds: null,
+    minHoldSeconds: null,
+    ...settings,
+  };
+}
+
+// Optional accounts a test can pass to mint/sell (e.g. auditLog, collectionStats)
+export type ExtraAccounts = Record<string, PublicKey | null>;
+
+export async function mintNft(
+  fixture: PoolFixture,
+  payer: Keypair,
+  extra: ExtraAccounts = {},
+  maxPrice = new BN(0)
+): Promise<PublicKey> {
+  return (await mintNftTx(fixture, payer, extra, maxPrice)).nftMint;
+}
+
+export async function mintNftTx(
+  fixture: PoolFixture,
+  payer: Keypair,
+  extra: ExtraAccounts = {},
+  maxPrice = new BN(0)
+): Promise<{ nftMint: PublicKey; signature: string }> {
+  const nftMint = Keypair.generate();
+  const signature = await program.methods
+    .mintNft('Test NFT', 'TNFT', 'https://example.com/nft.json', 500, maxPrice)
+    .accounts({
+      payer: payer.publicKey,
+      nftMint: nftMint.publicKey,
+      escrow: escrowPda(nftMint.publicKey),
+      pool: fixture.pool,
+      mintCounter: mintCounterPda(fixture.pool, payer.publicKey),
+      tokenAccount: getAssociatedTokenAddressSync(nftMint.publicKey, payer.publicKey),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadataAccount: metadataPda(nftMint.publicKey),
+      masterEdition: masterEditionPda(nftMint.publicKey),
+      collectionMint: fixture.collectionMint,
+      collectionMetadata: metadataPda(fixture.collectionMint),
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      platformWallet: PLATFORM_WALLET,
+      auditLog: null,
+      collectionStats: null,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+      ...extra,
+    })
+    .signers([payer, nftMint])
+    .rpc({ commitment: 'confirmed' });
+  return { nftMint: nftMint.publicKey, signature };
+}
+
+export function verifyAccounts(fixture: PoolFixture, authority: Keypair, nftMint: PublicKey) {
+  return {
+    authority: authority.publicKey,
+    pool: fixture.pool,
+    collectionMint: fixture.collectionMint,
+    collectionMetadata: metadataPda(fixture.collectionMint),
+    collectionMasterEdition: masterEditionPda(fixture.collectionMint),
+    nftMint,
+    escrow: escrowPda(nftMint),
+    metadataAccount: metadataPda(nftMint),
+    tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+  };
+}
+
+export async function verifyNft(fixture: PoolFixture, nftMint: PublicKey): Promise<string> {
+  return program.methods
+    .verifyCollectionItem()
+    .accounts(verifyAccounts(fixture, fixture.creator, nftMint))
+    .signers([fixture.creator])
+    .rpc({ commitment: 'confirmed' });
+}
+
+// sell_nft only buys back verified collection members, so unless told
+// otherwise the creator verifies the NFT first
+export async function sellNft(
+  fixture: PoolFixture,
+  seller: Keypair,
+  nftMint: PublicKey,
+  extra: ExtraAccounts = {},
+  minProceeds = new BN(0),
+  verify = true
+): Promise<string> {
+  if (verify && !(await fetchMetadataCollection(nftMint))?.verified) {
+    await verifyNft(fixture, nftMint);
+  }
+  return program.methods
+    .sellNft(minProceeds)
+    .accounts({
+      seller: seller.publicKey,
+      pool: fixture.pool,
+      escrow: escrowPda(nftMint),
+      creator: fixture.creator.publicKey,
+      nftMint,
+      sellerNftTokenAccount: getAssociatedTokenAddressSync(nftMint, seller.publicKey),
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      metadataAccount: metadataPda(nftMint),
+      masterEditionAccount: masterEditionPda(nftMint),
+      collectionMint: fixture.collectionMint,
+      collectionMetadata: metadataPda(fixture.collectionMint),
+      auditLog: null,
+      collectionStats: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      ...extra,
+    })
+    .signers([seller])
+    .rpc({ commitment: 'confirmed' });
+}
+
+export async function migrateToTensor(fixture: PoolFixture): Promise<string> {
+  return program.methods
+    .migrateToTensor()
+    .accounts({
+      authority: fixture.creator.publicKey,
+      pool: fixture.pool,
+      collectionMint: fixture.collectionMint,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([fixture.creator])
+    .rpc();
+}
+
+// Decodes the Anchor events emitted by a confirmed transaction
+export async function fetchEvents(signature: string): Promise<anchor.Event[]> {
+  const tx = await provider.connection.getTransaction(signature, {
+    commitment: 'confirmed',
+    maxSupportedTransactionVersion: 0,
+  });
+  const parser = new anchor.EventParser(program.programId, program.coder);
+  return Array.from(parser.parseLogs(tx?.meta?.logMessages ?? []));
+}
+
+// Event names are camelCased by some client versions, so match loosely
+export function findEvent(events: anchor.Event[], name: string): anchor.Event | undefined {
+  return events.find((e) => e.name.toLowerCase() === name.toLowerCase());
+}
+
+// Reads the (collection key, verified) pair out of a raw Metaplex metadata account
+export async function fetchMetadataCollection(
+  mint: PublicKey
+): Promise<{ key: PublicKey; verified: boolean } | null> {
+  const data = (await provider.connection.getAccountInfo(metadataPda(mint), 'confirmed')).data;
+  let offset = 1 + 32 + 32; // key, update_authority, mint
+  for (let i = 0; i < 3; i++) {
+    offset += 4 + data.readUInt32LE(offset); // name, symbol, uri
+  }
+  offset += 2; // seller_fee_basis_points
+  if (data[offset++] === 1) {
+    offset += 4 + data.readUInt32LE(offset) * 34; // creators
+  }
+  offset += 2; // primary_sale_happened, is_mutable
+  offset += data[offset] === 1 ? 2 : 1; // edition_nonce
+  offset += data[offset] === 1 ? 2 : 1; // token_standard
+  if (data[offset++] !== 1) {
+    return null;
+  }
+  return { verified: data[offset] === 1, key: new PublicKey(data.subarray(offset + 1, offset + 33)) };
+}
+
+export async function chainTime(): Promise<number> {
+  const slot = await provider.connection.getSlot('confirmed');
+  return (await provider.connection.getBlockTime(slot)) ?? Math.floor(Date.now() / 1000);
+}
+
+export function sleep(ms: number) {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+// Asserts the promise rejects with the given Anchor error name
+export async function expectError(promise: Promise<unknown>, errorName: string) {
+  try {
+    await promise;
+  } catch (err) {
+    assert.include(String(err), errorName);
+    return;
+  }
+  assert.fail(`expected ${errorName}`);
+}
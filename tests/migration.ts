@@ -0,0 +1,82 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  provider,
+  airdrop,
+  setupPool,
+  mintNft,
+  migrateToTensor,
+  expectError,
+  poolConfig,
+  PoolFixture,
+} from './helpers';
+
+describe('migration', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+
+  async function setMinSupply(minSupply: number) {
+    await program.methods
+      .updatePoolConfig(poolConfig({ minSupplyForMigration: new BN(minSupply) }))
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+  }
+
+  before(async () => {
+    await airdrop(user.publicKey, 800);
+
+    // 7 mints on a flat 100 SOL curve escrow 693 SOL, past the 690 SOL threshold
+    fixture = await setupPool(new BN(100 * LAMPORTS_PER_SOL), new BN(1_000_000));
+    for (let i = 0; i < 7; i++) {
+      await mintNft(fixture, user);
+    }
+  });
+
+  it('Rejects migration when market cap is met but supply is not', async () => {
+    await setMinSupply(8);
+    await expectError(migrateToTensor(fixture), 'ThresholdNotMet');
+  });
+
+  it('Migrates once both market cap and minimum supply are met', async () => {
+    await setMinSupply(7);
+    await migrateToTensor(fixture);
+
+    const pool = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.isTrue(pool.isMigratedToTensor);
+    assert.isFalse(pool.isActive);
+  });
+
+  it('Rejects minting from a migrated pool as AlreadyMigrated', async () => {
+    await expectError(mintNft(fixture, user), 'AlreadyMigrated');
+  });
+
+  it('Refuses to close a migrated pool while NFTs are outstanding', async () => {
+    const pool = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.equal(pool.currentSupply.toNumber(), 7);
+
+    await expectError(
+      program.methods
+        .closePool()
+        .accounts({
+          authority: fixture.creator.publicKey,
+          pool: fixture.pool,
+          collectionMint: fixture.collectionMint,
+          auditLog: null,
+          collectionStats: null,
+        })
+        .signers([fixture.creator])
+        .rpc(),
+      'PoolNotEmpty'
+    );
+
+    // The pool stays in place, so create_pool cannot re-initialize it
+    assert.isNotNull(await provider.connection.getAccountInfo(fixture.pool));
+  });
+});
@@ -0,0 +1,180 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  airdrop,
+  setupPool,
+  mintNft,
+  mintNftTx,
+  sellNft,
+  escrowPda,
+  fetchEvents,
+  findEvent,
+  expectError,
+  BASE_PRICE,
+  poolConfig,
+  PoolFixture,
+} from './helpers';
+
+describe('mint-sell', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+
+  before(async () => {
+    await airdrop(user.publicKey, 20);
+    fixture = await setupPool();
+  });
+
+  it('Emits old and new curve prices on mint and sell', async () => {
+    await mintNft(fixture, user);
+    const { nftMint, signature } = await mintNftTx(fixture, user);
+
+    const mintEvent = findEvent(await fetchEvents(signature), 'PriceHistoryEvent');
+    assert.ok(mintEvent);
+    assert.equal(mintEvent.data.supply.toNumber(), 2);
+    assert.equal(mintEvent.data.oldPrice.toNumber(), (BASE_PRICE.toNumber() * 12) / 10);
+    assert.equal(mintEvent.data.newPrice.toNumber(), (BASE_PRICE.toNumber() * 144) / 100);
+
+    const sellEvent = findEvent(await fetchEvents(await sellNft(fixture, user, nftMint)), 'PriceHistoryEvent');
+    assert.ok(sellEvent);
+    assert.equal(sellEvent.data.supply.toNumber(), 1);
+    assert.equal(sellEvent.data.oldPrice.toNumber(), mintEvent.data.newPrice.toNumber());
+    assert.equal(sellEvent.data.newPrice.toNumber(), mintEvent.data.oldPrice.toNumber());
+  });
+
+  it('Rejects a mint once the next curve price would overflow u64', async () => {
+    // 1 lamport, then 5 SOL, then 2.5e19 lamports which no u64 can hold
+    const aggressive = await setupPool(new BN(1), new BN('5000000000000000'));
+
+    await mintNft(aggressive, user);
+    await mintNft(aggressive, user);
+    await expectError(mintNft(aggressive, user), 'PriceCalculationOverflow');
+  });
+
+  it('Rejects a mint once the curve price passes max_price', async () => {
+    const capped = await setupPool();
+    const maxPrice = BASE_PRICE;
+
+    // The first mint is priced exactly at the cap
+    await mintNft(capped, user, {}, maxPrice);
+
+    // The curve has moved to 1.2x, above what the user agreed to pay
+    await expectError(mintNft(capped, user, {}, maxPrice), 'SlippageExceeded');
+  });
+
+  it('Rejects a sell when proceeds fall under min_proceeds', async () => {
+    const floored = await setupPool();
+
+    // Keep one NFT minted so the sold one is not the pool's last
+    await mintNft(floored, user);
+    const nftMint = await mintNft(floored, user);
+
+    // The escrow holds the 1.2x mint price, less the sell fee, so 2x is out of reach
+    const minProceeds = new BN(BASE_PRICE.toNumber() * 2);
+    await expectError(sellNft(floored, user, nftMint, {}, minProceeds), 'SlippageExceeded');
+
+    await sellNft(floored, user, nftMint);
+  });
+
+  it('Emits a single NftMint event carrying supply and escrow amount', async () => {
+    const fresh = await setupPool();
+    const { signature } = await mintNftTx(fresh, user);
+
+    const mintEvents = (await fetchEvents(signature)).filter((e) => e.name.toLowerCase() === 'nftmint');
+    assert.lengthOf(mintEvents, 1);
+    assert.equal(mintEvents[0].data.supply.toNumber(), 1);
+    // 1% protocol fee goes to the creator, the rest is escrowed
+    assert.equal(mintEvents[0].data.escrowAmount.toNumber(), (BASE_PRICE.toNumber() * 99) / 100);
+  });
+
+  it('Emits a single EscrowInitializedEvent with the escrowed amount', async () => {
+    const fresh = await setupPool();
+    const { nftMint, signature } = await mintNftTx(fresh, user);
+
+    const escrowEvents = (await fetchEvents(signature)).filter(
+      (e) => e.name.toLowerCase() === 'escrowinitializedevent'
+    );
+    assert.lengthOf(escrowEvents, 1);
+    const opened = escrowEvents[0].data;
+    assert.equal(opened.nftMint.toString(), nftMint.toString());
+    assert.equal(opened.escrow.toString(), escrowPda(nftMint).toString());
+    assert.equal(opened.lastPrice.toNumber(), BASE_PRICE.toNumber());
+    assert.equal(opened.amount.toNumber(), (BASE_PRICE.toNumber() * 99) / 100);
+  });
+
+  it("Sells a pool's only NFT and releases exactly what it escrowed", async () => {
+    const single = await setupPool();
+    const nftMint = await mintNft(single, user);
+
+    const minted = await program.account.bondingCurvePool.fetch(single.pool);
+    // The pool tracks the mint price net of the 1% fee, not the curve price
+    assert.equal(minted.totalEscrowed.toNumber(), (BASE_PRICE.toNumber() * 99) / 100);
+
+    await sellNft(single, user, nftMint);
+
+    const sold = await program.account.bondingCurvePool.fetch(single.pool);
+    assert.equal(sold.currentSupply.toNumber(), 0);
+    assert.equal(sold.totalEscrowed.toNumber(), 0);
+  });
+
+  it('Tags every emitted event with the current schema version', async () => {
+    const EVENT_SCHEMA_VERSION = 6;
+    const versioned = await setupPool();
+
+    await mintNft(versioned, user);
+    const { nftMint, signature } = await mintNftTx(versioned, user);
+    const events = [...(await fetchEvents(signature)), ...(await fetchEvents(await sellNft(versioned, user, nftMint)))];
+
+    assert.isAtLeast(events.length, 4);
+    for (const event of events) {
+      assert.equal(event.data.schemaVersion, EVENT_SCHEMA_VERSION, event.name);
+    }
+  });
+
+  it('Estimates the mint price in USD from the reference price', async () => {
+    const priced = await setupPool();
+    const centsPerSol = new BN(15_000); // $150.00 per SOL
+
+    await program.methods
+      .updatePoolConfig(poolConfig({ referencePrice: centsPerSol }))
+      .accounts({
+        authority: priced.creator.publicKey,
+        pool: priced.pool,
+        collectionMint: priced.collectionMint,
+      })
+      .signers([priced.creator])
+      .rpc();
+
+    const { signature } = await mintNftTx(priced, user);
+    const minted = findEvent(await fetchEvents(signature), 'NftMint').data;
+    // 0.01 SOL at $150 is 150 cents
+    assert.equal(
+      minted.usdEstimate.toNumber(),
+      Math.floor((minted.mintPrice.toNumber() * centsPerSol.toNumber()) / LAMPORTS_PER_SOL)
+    );
+    assert.equal(minted.usdEstimate.toNumber(), 150);
+  });
+
+  it('Numbers editions by total mints, even after a burn', async () => {
+    const numbered = await setupPool();
+    const edition = async (nftMint: PublicKey) =>
+      (await program.account.nftEscrow.fetch(escrowPda(nftMint))).editionNumber.toNumber();
+
+    const editions: number[] = [];
+    const minted: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      const nftMint = await mintNft(numbered, user);
+      minted.push(nftMint);
+      editions.push(await edition(nftMint));
+    }
+
+    await sellNft(numbered, user, minted[2]);
+    editions.push(await edition(await mintNft(numbered, user)));
+
+    assert.deepEqual(editions, [1, 2, 3, 4]);
+    const pool = await program.account.bondingCurvePool.fetch(numbered.pool);
+    assert.equal(pool.totalMinted.toNumber(), 4);
+    assert.equal(pool.currentSupply.toNumber(), 3);
+  });
+});
@@ -0,0 +1,385 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  airdrop,
+  setupPool,
+  mintNft,
+  sellNft,
+  chainTime,
+  sleep,
+  mintNftTx,
+  fetchEvents,
+  findEvent,
+  expectError,
+  provider,
+  createCollection,
+  poolPda,
+  BASE_PRICE,
+  GROWTH_FACTOR,
+  PLATFORM_WALLET,
+  poolConfig,
+  PoolFixture,
+} from './helpers';
+
+// PauseState operation bits
+const PAUSE_MINT = 1 << 0;
+const PAUSE_SELL = 1 << 2;
+const PAUSE_ALL = 0b111;
+
+describe('pool-admin', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+
+  before(async () => {
+    await airdrop(user.publicKey);
+    fixture = await setupPool();
+  });
+
+  it('Pauses the pool and unpauses it manually', async () => {
+    await program.methods
+      .emergencyPause(PAUSE_ALL, 'Investigating pricing issue', new BN(0))
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    const paused = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.equal(paused.pauseState.reason, 'Investigating pricing issue');
+    assert.equal(paused.pauseState.autoUnpauseAt.toNumber(), 0);
+    await expectError(mintNft(fixture, user), 'PoolPaused');
+
+    await program.methods
+      .unpausePool()
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    const unpaused = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.isNull(unpaused.pauseState);
+    await mintNft(fixture, user);
+  });
+
+  it('Lifts the pause automatically once auto_unpause_at passes', async () => {
+    const autoUnpauseAt = (await chainTime()) + 3;
+    await program.methods
+      .emergencyPause(PAUSE_ALL, 'Short maintenance window', new BN(autoUnpauseAt))
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    await expectError(mintNft(fixture, user), 'PoolPaused');
+
+    while ((await chainTime()) <= autoUnpauseAt) {
+      await sleep(1000);
+    }
+    await mintNft(fixture, user);
+  });
+
+  it('Blocks only minting under a mint-only pause', async () => {
+    const nftMint = await mintNft(fixture, user);
+
+    await program.methods
+      .emergencyPause(PAUSE_MINT, 'Mint halted, sells stay open', new BN(0))
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    const paused = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.equal(paused.pauseState.pausedOperations & PAUSE_SELL, 0);
+
+    await expectError(mintNft(fixture, user), 'PoolPaused');
+    await sellNft(fixture, user, nftMint);
+
+    await program.methods
+      .unpausePool()
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+  });
+
+  it("Keeps a paused pool's NFTs from trading through another pool", async () => {
+    const nftMint = await mintNft(fixture, user);
+    const other = await setupPool();
+
+    await program.methods
+      .emergencyPause(PAUSE_ALL, 'Paused while another pool stays open', new BN(0))
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    await expectError(mintNft(fixture, user, { pool: other.pool }), 'ConstraintSeeds');
+    await expectError(sellNft(fixture, user, nftMint, { pool: other.pool }), 'ConstraintSeeds');
+
+    await program.methods
+      .unpausePool()
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+  });
+
+  it('Rejects a pause from someone other than the creator', async () => {
+    await expectError(
+      program.methods
+        .emergencyPause(PAUSE_ALL, 'Not my pool', new BN(0))
+        .accounts({
+          authority: user.publicKey,
+          pool: fixture.pool,
+          collectionMint: fixture.collectionMint,
+        })
+        .signers([user])
+        .rpc(),
+      'InvalidAuthority'
+    );
+  });
+
+  it('Hands the pool to a new creator in two steps', async () => {
+    const newCreator = Keypair.generate();
+    await airdrop(newCreator.publicKey, 1);
+
+    await program.methods
+      .transferPoolAuthority(newCreator.publicKey)
+      .accounts({
+        authority: fixture.creator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([fixture.creator])
+      .rpc();
+
+    const proposed = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.equal(proposed.creator.toString(), fixture.creator.publicKey.toString());
+    assert.equal(proposed.pendingCreator.toString(), newCreator.publicKey.toString());
+
+    // Someone other than the pending creator cannot take over
+    await expectError(
+      program.methods
+        .acceptPoolAuthority()
+        .accounts({
+          newCreator: user.publicKey,
+          pool: fixture.pool,
+          collectionMint: fixture.collectionMint,
+        })
+        .signers([user])
+        .rpc(),
+      'InvalidAuthority'
+    );
+
+    await program.methods
+      .acceptPoolAuthority()
+      .accounts({
+        newCreator: newCreator.publicKey,
+        pool: fixture.pool,
+        collectionMint: fixture.collectionMint,
+      })
+      .signers([newCreator])
+      .rpc();
+
+    const accepted = await program.account.bondingCurvePool.fetch(fixture.pool);
+    assert.equal(accepted.creator.toString(), newCreator.publicKey.toString());
+    assert.isNull(accepted.pendingCreator);
+
+    fixture.creator = newCreator;
+  });
+
+  it('Closes an empty pool and refunds its rent', async () => {
+    const empty = await setupPool();
+    const before = await provider.connection.getBalance(empty.creator.publicKey);
+
+    await program.methods
+      .closePool()
+      .accounts({
+        authority: empty.creator.publicKey,
+        pool: empty.pool,
+        collectionMint: empty.collectionMint,
+        auditLog: null,
+        collectionStats: null,
+      })
+      .signers([empty.creator])
+      .rpc({ commitment: 'confirmed' });
+
+    assert.isNull(await provider.connection.getAccountInfo(empty.pool, 'confirmed'));
+    assert.isAbove(await provider.connection.getBalance(empty.creator.publicKey, 'confirmed'), before);
+  });
+
+  it('Refuses to close a pool with minted NFTs', async () => {
+    await expectError(
+      program.methods
+        .closePool()
+        .accounts({
+          authority: fixture.creator.publicKey,
+          pool: fixture.pool,
+          collectionMint: fixture.collectionMint,
+          auditLog: null,
+          collectionStats: null,
+        })
+        .signers([fixture.creator])
+        .rpc(),
+      'PoolNotEmpty'
+    );
+  });
+
+  it('Routes a configured 2.5% mint fee to the creator', async () => {
+    const feePool = await setupPool();
+    const configure = (mintFeeBp: number, signer: Keypair) =>
+      program.methods
+        .updatePoolConfig(poolConfig({ mintFeeBp }))
+        .accounts({
+          authority: signer.publicKey,
+          pool: feePool.pool,
+          collectionMint: feePool.collectionMint,
+        })
+        .signers([signer])
+        .rpc();
+
+    await expectError(configure(1001, feePool.creator), 'InvalidMintFee');
+    await configure(250, feePool.creator);
+
+    const { signature } = await mintNftTx(feePool, user);
+    const minted = findEvent(await fetchEvents(signature), 'NftMint').data;
+    assert.equal(minted.protocolFee.toNumber(), (BASE_PRICE.toNumber() * 250) / 10_000);
+    assert.equal(minted.escrowAmount.toNumber(), BASE_PRICE.toNumber() - minted.protocolFee.toNumber());
+  });
+
+  it('Sends mint fees to the platform wallet and sell fees to the creator', async () => {
+    const split = await setupPool();
+
+    const balance = (key: PublicKey) => provider.connection.getBalance(key, 'confirmed');
+    const creatorBefore = await balance(split.creator.publicKey);
+    const platformBefore = await balance(PLATFORM_WALLET);
+
+    const { nftMint, signature } = await mintNftTx(split, user);
+    const minted = findEvent(await fetchEvents(signature), 'NftMint').data;
+    const platformAfterMint = platformBefore + minted.protocolFee.toNumber();
+    assert.equal(await balance(PLATFORM_WALLET), platformAfterMint);
+    assert.equal(await balance(split.creator.publicKey), creatorBefore);
+
+    const sold = findEvent(await fetchEvents(await sellNft(split, user, nftMint)), 'NftSale').data;
+    assert.equal(await balance(split.creator.publicKey), creatorBefore + sold.sellFee.toNumber());
+    assert.equal(await balance(PLATFORM_WALLET), platformAfterMint);
+  });
+
+  it('Rejects a pool whose mint fees would go to a creator-chosen wallet', async () => {
+    const creator = Keypair.generate();
+    await airdrop(creator.publicKey);
+    const collectionMint = await createCollection(creator);
+
+    await expectError(
+      program.methods
+        .createPool(BASE_PRICE, GROWTH_FACTOR)
+        .accounts({
+          creator: creator.publicKey,
+          collectionMint,
+          pool: poolPda(collectionMint),
+          platformWallet: creator.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc(),
+      'ConstraintAddress'
+    );
+  });
+
+  it('Holds back a second mint from the same wallet until the cooldown passes', async () => {
+    const cooled = await setupPool();
+    await program.methods
+      .updatePoolConfig(poolConfig({ mintCooldownSeconds: new BN(3) }))
+      .accounts({
+        authority: cooled.creator.publicKey,
+        pool: cooled.pool,
+        collectionMint: cooled.collectionMint,
+      })
+      .signers([cooled.creator])
+      .rpc();
+
+    await mintNft(cooled, user);
+    const readyAt = (await chainTime()) + 3;
+    await expectError(mintNft(cooled, user), 'CooldownActive');
+
+    while ((await chainTime()) <= readyAt) {
+      await sleep(1000);
+    }
+    await mintNft(cooled, user);
+  });
+
+  it('Rejects selling an NFT before the minimum hold time passes', async () => {
+    const held = await setupPool();
+    await program.methods
+      .updatePoolConfig(poolConfig({ minHoldSeconds: new BN(3) }))
+      .accounts({
+        authority: held.creator.publicKey,
+        pool: held.pool,
+        collectionMint: held.collectionMint,
+      })
+      .signers([held.creator])
+      .rpc();
+
+    const nftMint = await mintNft(held, user);
+    const sellableAt = (await chainTime()) + 3;
+    await expectError(sellNft(held, user, nftMint), 'HoldPeriodActive');
+
+    // A pool without a hold period cannot be used to sell it early
+    const open = await setupPool();
+    await expectError(
+      sellNft(held, user, nftMint, {
+        pool: open.pool,
+        creator: open.creator.publicKey,
+        collectionMint: open.collectionMint,
+      }),
+      'InvalidPool'
+    );
+
+    while ((await chainTime()) <= sellableAt) {
+      await sleep(1000);
+    }
+    await sellNft(held, user, nftMint);
+  });
+
+  it('Creates pools on the current layout, which migrate_pool_account leaves alone', async () => {
+    const current = await setupPool();
+    const pool = await program.account.bondingCurvePool.fetch(current.pool);
+    assert.equal(pool.layoutVersion, 2);
+
+    await expectError(
+      program.methods
+        .migratePoolAccount()
+        .accounts({
+          authority: current.creator.publicKey,
+          pool: current.pool,
+          collectionMint: current.collectionMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([current.creator])
+        .rpc(),
+      'PoolLayoutCurrent'
+    );
+  });
+});
@@ -0,0 +1,151 @@
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { assert } from 'chai';
+import * as fs from 'fs';
+import {
+  program,
+  provider,
+  airdrop,
+  setupPool,
+  poolPda,
+  escrowPda,
+  metadataPda,
+  fetchEvents,
+  findEvent,
+  expectError,
+  BASE_PRICE,
+  GROWTH_FACTOR,
+} from './helpers';
+
+// tests/fixtures/legacy-pool.json: a pool as the v1 program wrote it, 180 bytes,
+// one NFT minted at 0.01 SOL, created by tests/fixtures/legacy-creator.json.
+// legacy-escrow.json holds that NFT's 57-byte escrow and legacy-nft-metadata.json
+// its metadata, naming the legacy collection
+const LEGACY_COLLECTION = new PublicKey('9KiYus2WhJStbJwHbAthmjRTgNaw1NMHdcpQAKA1egxu');
+const LEGACY_NFT_MINT = new PublicKey('GUVVZ2sRCRobEv7iEhjpWwPxPeNHBwTb6379jhjSxCMg');
+const LEGACY_POOL_SIZE = 180;
+const LEGACY_ESCROW_SIZE = 57;
+
+describe('pool layout', () => {
+  const creator = Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(fs.readFileSync('tests/fixtures/legacy-creator.json', 'utf8')))
+  );
+  const pool = poolPda(LEGACY_COLLECTION);
+  const escrow = escrowPda(LEGACY_NFT_MINT);
+
+  function migratePool(authority: Keypair) {
+    return program.methods
+      .migratePoolAccount()
+      .accounts({
+        authority: authority.publicKey,
+        pool,
+        collectionMint: LEGACY_COLLECTION,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  function migrateEscrow(collectionMint: PublicKey) {
+    return program.methods
+      .migrateEscrowAccount()
+      .accounts({
+        payer: provider.wallet.publicKey,
+        pool: poolPda(collectionMint),
+        collectionMint,
+        nftMint: LEGACY_NFT_MINT,
+        escrow,
+        metadataAccount: metadataPda(LEGACY_NFT_MINT),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  function quoteSell() {
+    return program.methods
+      .quoteSell()
+      .accounts({ pool, nftMint: LEGACY_NFT_MINT, escrow })
+      .rpc({ commitment: 'confirmed' });
+  }
+
+  before(async () => {
+    await airdrop(creator.publicKey);
+  });
+
+  it('Loads the v1 pool fixture at its original size', async () => {
+    const info = await provider.connection.getAccountInfo(pool);
+    assert.isTrue(info.owner.equals(program.programId));
+    assert.equal(info.data.length, LEGACY_POOL_SIZE);
+  });
+
+  it('Rejects instructions on a v1 pool until it is migrated', async () => {
+    await expectError(program.methods.quoteMint().accounts({ pool }).rpc(), 'AccountDidNotDeserialize');
+  });
+
+  it('Only lets the pool creator migrate a v1 pool', async () => {
+    const stranger = Keypair.generate();
+    await airdrop(stranger.publicKey);
+    await expectError(migratePool(stranger), 'InvalidAuthority');
+  });
+
+  it('Migrates a v1 pool, keeping its fields and defaulting the new ones', async () => {
+    await migratePool(creator);
+
+    const info = await provider.connection.getAccountInfo(pool);
+    assert.isAbove(info.data.length, LEGACY_POOL_SIZE);
+    assert.isAtLeast(info.lamports, await provider.connection.getMinimumBalanceForRentExemption(info.data.length));
+
+    const migrated = await program.account.bondingCurvePool.fetch(pool);
+    assert.equal(migrated.layoutVersion, 2);
+    assert.isTrue(migrated.collection.equals(LEGACY_COLLECTION));
+    assert.isTrue(migrated.creator.equals(creator.publicKey));
+    assert.equal(migrated.basePrice.toString(), BASE_PRICE.toString());
+    assert.equal(migrated.growthFactor.toString(), GROWTH_FACTOR.toString());
+    assert.equal(migrated.currentSupply.toNumber(), 1);
+    assert.equal(migrated.totalEscrowed.toNumber(), 10_000_000);
+    assert.isTrue(migrated.isActive);
+    assert.equal(migrated.mintFeeBp, 100);
+    assert.equal(migrated.totalMinted.toNumber(), 1);
+    assert.isNull(migrated.pendingCreator);
+  });
+
+  it('Serves a migrated pool and refuses to migrate it twice', async () => {
+    await program.methods.quoteMint().accounts({ pool }).rpc();
+    await expectError(migratePool(creator), 'PoolLayoutCurrent');
+  });
+
+  it('Rejects a v1 escrow until it is migrated', async () => {
+    const info = await provider.connection.getAccountInfo(escrow);
+    assert.equal(info.data.length, LEGACY_ESCROW_SIZE);
+    await expectError(quoteSell(), 'AccountDidNotDeserialize');
+  });
+
+  it('Refuses to bind a v1 escrow to a pool outside its NFT collection', async () => {
+    const other = await setupPool();
+    await expectError(migrateEscrow(other.collectionMint), 'InvalidCollection');
+  });
+
+  it('Migrates a v1 escrow into the pool its NFT collection names', async () => {
+    const before = await provider.connection.getAccountInfo(escrow);
+    await migrateEscrow(LEGACY_COLLECTION);
+
+    const info = await provider.connection.getAccountInfo(escrow);
+    const rentAdded =
+      (await provider.connection.getMinimumBalanceForRentExemption(info.data.length)) -
+      (await provider.connection.getMinimumBalanceForRentExemption(LEGACY_ESCROW_SIZE));
+    assert.isAbove(info.data.length, LEGACY_ESCROW_SIZE);
+    assert.equal(info.lamports, before.lamports + rentAdded);
+
+    const migrated = await program.account.nftEscrow.fetch(escrow);
+    assert.isTrue(migrated.pool.equals(pool));
+    assert.isTrue(migrated.nftMint.equals(LEGACY_NFT_MINT));
+    assert.equal(migrated.lamports.toNumber(), 10_000_000);
+    assert.equal(migrated.editionNumber.toNumber(), 0);
+    assert.equal(migrated.mintedAt.toNumber(), 0);
+
+    // The escrowed 0.01 SOL is still what a sale would pay out of
+    const quote = findEvent(await fetchEvents(await quoteSell()), 'SellQuoteEvent').data;
+    assert.equal(quote.gross.toNumber(), 10_000_000);
+
+    await expectError(migrateEscrow(LEGACY_COLLECTION), 'EscrowLayoutCurrent');
+  });
+});
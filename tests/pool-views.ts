@@ -0,0 +1,181 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair } from '@solana/web3.js';
+import { assert } from 'chai';
+import {
+  program,
+  airdrop,
+  setupPool,
+  mintNft,
+  mintNftTx,
+  sellNft,
+  escrowPda,
+  fetchEvents,
+  findEvent,
+  expectError,
+  BASE_PRICE,
+  GROWTH_FACTOR,
+  poolConfig,
+  PoolFixture,
+} from './helpers';
+
+describe('pool-views', () => {
+  const user = Keypair.generate();
+  let fixture: PoolFixture;
+
+  before(async () => {
+    await airdrop(user.publicKey);
+    fixture = await setupPool();
+  });
+
+  async function supplyForPrice(targetPrice: BN) {
+    const signature = await program.methods
+      .viewSupplyForPrice(targetPrice)
+      .accounts({ pool: fixture.pool })
+      .rpc({ commitment: 'confirmed' });
+    return findEvent(await fetchEvents(signature), 'SupplyForPriceEvent').data;
+  }
+
+  it('Returns supply 0 when the base price already meets the target', async () => {
+    const result = await supplyForPrice(BASE_PRICE);
+    assert.equal(result.supply.toNumber(), 0);
+    assert.equal(result.priceAtSupply.toNumber(), BASE_PRICE.toNumber());
+  });
+
+  it('Finds the first supply whose price reaches the target', async () => {
+    // 0.01 SOL at 1.2x per mint: 0.01, 0.012, 0.0144, 0.01728, 0.020736
+    const result = await supplyForPrice(new BN(20_000_000));
+    assert.equal(result.supply.toNumber(), 4);
+    assert.equal(result.priceAtSupply.toNumber(), 20_736_000);
+
+    // One step below must still be under the target
+    const previous = await supplyForPrice(new BN(17_280_000));
+    assert.equal(previous.supply.toNumber(), 3);
+    assert.isBelow(previous.priceAtSupply.toNumber(), 20_000_000);
+  });
+
+  it('Quotes exactly what a following sell pays out', async () => {
+    const quoted = await setupPool();
+    await mintNft(quoted, user);
+    const nftMint = await mintNft(quoted, user);
+
+    const quoteSignature = await program.methods
+      .quoteSell()
+      .accounts({ pool: quoted.pool, nftMint, escrow: escrowPda(nftMint) })
+      .rpc({ commitment: 'confirmed' });
+    const quote = findEvent(await fetchEvents(quoteSignature), 'SellQuoteEvent').data;
+    assert.equal(quote.gross.toNumber(), quote.fee.toNumber() + quote.netToSeller.toNumber());
+
+    const sale = findEvent(await fetchEvents(await sellNft(quoted, user, nftMint)), 'NftSale').data;
+    assert.equal(sale.salePrice.toNumber(), quote.netToSeller.toNumber());
+    assert.equal(sale.sellFee.toNumber(), quote.fee.toNumber());
+  });
+
+  it("Refuses to quote a sale against a pool that didn't mint the NFT", async () => {
+    const minted = await setupPool();
+    const other = await setupPool();
+    const nftMint = await mintNft(minted, user);
+
+    await expectError(
+      program.methods.quoteSell().accounts({ pool: other.pool, nftMint, escrow: escrowPda(nftMint) }).rpc(),
+      'InvalidPool'
+    );
+  });
+
+  it('Quotes the same price and fee split that a following mint charges', async () => {
+    const quoted = await setupPool();
+    await mintNft(quoted, user);
+
+    const quoteSignature = await program.methods
+      .quoteMint()
+      .accounts({ pool: quoted.pool })
+      .rpc({ commitment: 'confirmed' });
+    const quote = findEvent(await fetchEvents(quoteSignature), 'MintQuoteEvent').data;
+    assert.equal(quote.supply.toNumber(), 1);
+    assert.equal(quote.price.toNumber(), quote.platformFee.toNumber() + quote.escrowAmount.toNumber());
+
+    const { signature } = await mintNftTx(quoted, user);
+    const minted = findEvent(await fetchEvents(signature), 'NftMint').data;
+    assert.equal(minted.mintPrice.toNumber(), quote.price.toNumber());
+    assert.equal(minted.protocolFee.toNumber(), quote.platformFee.toNumber());
+    assert.equal(minted.escrowAmount.toNumber(), quote.escrowAmount.toNumber());
+  });
+
+  it('Records each executed mint price in the pool history', async () => {
+    const tracked = await setupPool();
+    for (let i = 0; i < 3; i++) {
+      await mintNft(tracked, user);
+    }
+
+    const signature = await program.methods
+      .viewPriceHistory()
+      .accounts({ pool: tracked.pool })
+      .rpc({ commitment: 'confirmed' });
+    const history = findEvent(await fetchEvents(signature), 'PriceHistorySnapshotEvent').data;
+
+    const prices = history.prices.map((p: BN) => p.toNumber());
+    assert.deepEqual(prices, [10_000_000, 12_000_000, 14_400_000]);
+    assert.lengthOf(history.timestamps, 3);
+
+    const pool = await program.account.bondingCurvePool.fetch(tracked.pool);
+    assert.equal(pool.priceHistoryIdx, 3);
+  });
+
+  it('Reports the share of market cap backed by escrow', async () => {
+    // 1.5x curve with a 4% mint fee: escrow 9.6M + 14.4M against a 2 x 15M market cap
+    const backed = await setupPool(BASE_PRICE, new BN(1_500_000));
+    await program.methods
+      .updatePoolConfig(poolConfig({ mintFeeBp: 400 }))
+      .accounts({
+        authority: backed.creator.publicKey,
+        pool: backed.pool,
+        collectionMint: backed.collectionMint,
+      })
+      .signers([backed.creator])
+      .rpc();
+    await mintNft(backed, user);
+    await mintNft(backed, user);
+
+    const signature = await program.methods
+      .viewPoolStats()
+      .accounts({ pool: backed.pool })
+      .rpc({ commitment: 'confirmed' });
+    const stats = findEvent(await fetchEvents(signature), 'PoolStatsEvent').data;
+
+    assert.equal(stats.totalEscrowed.toNumber(), 24_000_000);
+    assert.equal(stats.marketCap.toNumber(), 30_000_000);
+    assert.equal(stats.backingRatioBp, 8000);
+  });
+
+  it('Keeps the cached market cap in step with mints and sells', async () => {
+    const cached = await setupPool();
+
+    // Walks the curve off-chain, rounding down each step like the program does
+    const expectedMarketCap = (supply: number) => {
+      let lastPrice = BASE_PRICE;
+      for (let i = 1; i < supply; i++) {
+        lastPrice = lastPrice.mul(GROWTH_FACTOR).div(new BN(1_000_000));
+      }
+      return lastPrice.muln(supply).toNumber();
+    };
+    const checkCache = async (expectedSupply: number) => {
+      const pool = await program.account.bondingCurvePool.fetch(cached.pool);
+      assert.equal(pool.cachedAtSupply.toNumber(), expectedSupply);
+      assert.equal(pool.cachedMarketCap.toNumber(), expectedMarketCap(expectedSupply));
+
+      const signature = await program.methods
+        .viewPoolStats()
+        .accounts({ pool: cached.pool })
+        .rpc({ commitment: 'confirmed' });
+      const stats = findEvent(await fetchEvents(signature), 'PoolStatsEvent').data;
+      assert.equal(stats.marketCap.toNumber(), expectedMarketCap(expectedSupply));
+    };
+
+    await mintNft(cached, user);
+    await mintNft(cached, user);
+    const nftMint = await mintNft(cached, user);
+    await checkCache(3);
+
+    await sellNft(cached, user, nftMint);
+    await checkCache(2);
+  });
+});
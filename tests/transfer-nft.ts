@@ -0,0 +1,60 @@
+import { Keypair, SystemProgram } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { assert } from 'chai';
+import {
+  program,
+  provider,
+  airdrop,
+  setupPool,
+  mintNft,
+  sellNft,
+  escrowPda,
+  fetchEvents,
+  findEvent,
+  PoolFixture,
+} from './helpers';
+
+describe('transfer-nft', () => {
+  const owner = Keypair.generate();
+  const recipient = Keypair.generate();
+  let fixture: PoolFixture;
+
+  before(async () => {
+    await airdrop(owner.publicKey);
+    await airdrop(recipient.publicKey, 1);
+    fixture = await setupPool();
+  });
+
+  it('Moves an NFT to a new holder who can then sell it against its escrow', async () => {
+    await mintNft(fixture, owner);
+    const nftMint = await mintNft(fixture, owner);
+    const escrow = escrowPda(nftMint);
+    const escrowBefore = await provider.connection.getBalance(escrow, 'confirmed');
+
+    const signature = await program.methods
+      .transferNft()
+      .accounts({
+        owner: owner.publicKey,
+        recipient: recipient.publicKey,
+        nftMint,
+        escrow,
+        ownerTokenAccount: getAssociatedTokenAddressSync(nftMint, owner.publicKey),
+        recipientTokenAccount: getAssociatedTokenAddressSync(nftMint, recipient.publicKey),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner])
+      .rpc({ commitment: 'confirmed' });
+
+    const event = findEvent(await fetchEvents(signature), 'NftTransferredEvent');
+    assert.ok(event);
+    assert.equal(event.data.to.toString(), recipient.publicKey.toString());
+    assert.equal(await provider.connection.getBalance(escrow, 'confirmed'), escrowBefore);
+
+    const recipientBefore = await provider.connection.getBalance(recipient.publicKey, 'confirmed');
+    const sale = findEvent(await fetchEvents(await sellNft(fixture, recipient, nftMint)), 'NftSale');
+    assert.equal(sale.data.seller.toString(), recipient.publicKey.toString());
+    assert.isAbove(await provider.connection.getBalance(recipient.publicKey, 'confirmed'), recipientBefore);
+  });
+});